@@ -44,3 +44,26 @@ typedef struct ArrowSchema ArrowSchema;
 typedef struct ArrowArray ArrowArray;
 
 #endif // ARROW_C_DATA_INTERFACE
+
+#ifndef ARROW_C_STREAM_INTERFACE
+#define ARROW_C_STREAM_INTERFACE
+
+struct ArrowArrayStream {
+  // Callback to get the stream type
+  int (*get_schema)(struct ArrowArrayStream *, struct ArrowSchema *out);
+
+  // Callback to get the next array
+  int (*get_next)(struct ArrowArrayStream *, struct ArrowArray *out);
+
+  // Callback to get optional detailed error information
+  const char *(*get_last_error)(struct ArrowArrayStream *);
+
+  // Release callback
+  void (*release)(struct ArrowArrayStream *);
+  // Opaque producer-specific data
+  void *private_data;
+};
+
+typedef struct ArrowArrayStream ArrowArrayStream;
+
+#endif // ARROW_C_STREAM_INTERFACE
@@ -6,6 +6,66 @@
 #include <stdlib.h>
 #include "arrow.h"
 
+typedef enum polars_csv_encoding_t {
+  PolarsCsvEncodingUtf8,
+  /**
+   * Invalid utf8 bytes are replaced with the replacement character.
+   */
+  PolarsCsvEncodingLossyUtf8,
+} polars_csv_encoding_t;
+
+/**
+ * The kind of a `polars_error_t`, it mirrors the variants of `PolarsError`.
+ */
+typedef enum polars_error_kind_t {
+  PolarsErrorKindColumnNotFound,
+  PolarsErrorKindSchemaMismatch,
+  PolarsErrorKindComputeError,
+  PolarsErrorKindInvalidOperation,
+  PolarsErrorKindIo,
+  PolarsErrorKindShapeMismatch,
+  PolarsErrorKindOutOfBounds,
+  PolarsErrorKindNoData,
+  PolarsErrorKindDuplicate,
+  PolarsErrorKindUtf8,
+  /**
+   * A panic was caught at the boundary of the C API.
+   */
+  PolarsErrorKindPanic,
+} polars_error_kind_t;
+
+typedef enum polars_ipc_compression_t {
+  PolarsIpcCompressionUncompressed,
+  PolarsIpcCompressionLZ4,
+  PolarsIpcCompressionZSTD,
+} polars_ipc_compression_t;
+
+typedef enum polars_parallel_strategy_t {
+  PolarsParallelStrategyAuto,
+  PolarsParallelStrategyNone,
+  PolarsParallelStrategyColumns,
+  PolarsParallelStrategyRowGroups,
+} polars_parallel_strategy_t;
+
+typedef enum polars_parquet_compression_t {
+  PolarsParquetCompressionUncompressed,
+  PolarsParquetCompressionSnappy,
+  PolarsParquetCompressionGzip,
+  PolarsParquetCompressionLzo,
+  PolarsParquetCompressionBrotli,
+  PolarsParquetCompressionZstd,
+  PolarsParquetCompressionLz4Raw,
+} polars_parquet_compression_t;
+
+typedef enum polars_quote_style_t {
+  /**
+   * Quotes fields only when they contain a quote, the separator or a line terminator.
+   */
+  PolarsQuoteStyleNecessary,
+  PolarsQuoteStyleAlways,
+  PolarsQuoteStyleNonNumeric,
+} polars_quote_style_t;
+
 typedef enum polars_value_type_t {
   PolarsValueTypeNull,
   PolarsValueTypeBoolean,
@@ -26,6 +86,12 @@ typedef enum polars_value_type_t {
   PolarsValueTypeUnknown,
 } polars_value_type_t;
 
+/**
+ * A CSV reader returning the file as a sequence of DataFrames, see
+ * `polars_csv_batched_reader_next_batches`.
+ */
+typedef struct polars_csv_batched_reader_t polars_csv_batched_reader_t;
+
 typedef struct polars_dataframe_t polars_dataframe_t;
 
 typedef struct polars_error_t polars_error_t;
@@ -36,23 +102,347 @@ typedef struct polars_lazy_frame_t polars_lazy_frame_t;
 
 typedef struct polars_lazy_group_by_t polars_lazy_group_by_t;
 
+/**
+ * A parquet writer appending DataFrames with the same schema to a single file.
+ */
+typedef struct polars_parquet_batched_writer_t polars_parquet_batched_writer_t;
+
+typedef struct polars_parquet_metadata_t polars_parquet_metadata_t;
+
 typedef struct polars_series_t polars_series_t;
 
+/**
+ * A value which owns its data, it stays valid after the series it was taken from is destroyed.
+ */
 typedef struct polars_value_t polars_value_t;
 
+/**
+ * The checks run on imported Arrow arrays, use `polars_carrow_validation_default` to get the
+ * default checks. The lengths and offsets of the arrays must not be negative whatever the
+ * checks.
+ */
+typedef struct polars_carrow_validation_t {
+  /**
+   * Checks that offsets are increasing and that the offsets of lists and maps are within the
+   * length of their values.
+   */
+  bool offsets;
+  /**
+   * Checks that string values are valid UTF-8, requires valid offsets.
+   */
+  bool utf8;
+  /**
+   * Checks that the numbers of buffers and children match the data type and that the children
+   * of structs and fixed size lists are at least as long as their parent.
+   */
+  bool lengths;
+  /**
+   * Checks that the keys of dictionaries are within the length of their values.
+   */
+  bool keys;
+} polars_carrow_validation_t;
+
 /**
  * The callback provided for display functions, returns -1 on error.
  */
 typedef intptr_t (*IOCallback)(const void *user, const uint8_t *data, uintptr_t len);
 
+/**
+ * Options used by `polars_dataframe_write_parquet_with_options`, use
+ * `polars_parquet_write_options_default` to get the default options.
+ */
+typedef struct polars_parquet_write_options_t {
+  enum polars_parquet_compression_t compression;
+  /**
+   * Compression level used by gzip, brotli and zstd, a negative level uses the codec default.
+   */
+  int32_t compression_level;
+  /**
+   * Whether to compute and write column statistics.
+   */
+  bool statistics;
+  /**
+   * Number of rows per row group, `SIZE_MAX` uses the polars default.
+   */
+  uintptr_t row_group_size;
+  /**
+   * Size limit of data pages in bytes, `SIZE_MAX` uses the polars default.
+   */
+  uintptr_t data_pagesize_limit;
+} polars_parquet_write_options_t;
+
+/**
+ * Options used by the parquet readers, use `polars_parquet_read_options_default` to get the
+ * default options.
+ */
+typedef struct polars_parquet_read_options_t {
+  /**
+   * Names of the columns to read, all columns are read when both `n_columns` and
+   * `n_projection` are 0.
+   */
+  const uint8_t *const *columns;
+  const uintptr_t *columns_lens;
+  uintptr_t n_columns;
+  /**
+   * Indices of the columns to read, ignored when columns are selected by name.
+   */
+  const uintptr_t *projection;
+  uintptr_t n_projection;
+  /**
+   * Stop reading after this number of rows, `SIZE_MAX` reads all the rows.
+   */
+  uintptr_t n_rows;
+  /**
+   * Name of the row count column, no row count column is added when empty.
+   */
+  const uint8_t *row_count_name;
+  uintptr_t row_count_name_len;
+  uint32_t row_count_offset;
+  enum polars_parallel_strategy_t parallel;
+  /**
+   * Whether to use the statistics to skip pages which cannot match.
+   */
+  bool use_statistics;
+  /**
+   * Whether to aggregate the chunks into a single chunk once read.
+   */
+  bool rechunk;
+} polars_parquet_read_options_t;
+
+/**
+ * The callback used to read at most `len` bytes into `data`, returns the number of bytes read,
+ * 0 at the end of the stream and -1 on error.
+ */
+typedef intptr_t (*ReadCallback)(const void *user, uint8_t *data, uintptr_t len);
+
+/**
+ * The callback used to move the stream position, `whence` is 0 for an offset relative to the
+ * start of the stream, 1 for the current position and 2 for the end of the stream (as in
+ * `lseek`). Returns the new position from the start of the stream or -1 on error.
+ */
+typedef int64_t (*SeekCallback)(const void *user, int64_t offset, int32_t whence);
+
+/**
+ * The callback returning the current position from the start of the stream or -1 on error.
+ */
+typedef int64_t (*TellCallback)(const void *user);
+
+/**
+ * A host provided stream which readers can use in place of a file path.
+ */
+typedef struct polars_reader_callbacks_t {
+  const void *user;
+  ReadCallback read;
+  SeekCallback seek;
+  TellCallback tell;
+} polars_reader_callbacks_t;
+
+/**
+ * Options used by `polars_dataframe_read_ipc`, use `polars_ipc_read_options_default` to get the
+ * default options.
+ */
+typedef struct polars_ipc_read_options_t {
+  /**
+   * Names of the columns to read, all columns are read when `n_columns` is 0.
+   */
+  const uint8_t *const *columns;
+  const uintptr_t *columns_lens;
+  uintptr_t n_columns;
+  /**
+   * Whether to memory-map the file instead of reading it into memory.
+   */
+  bool memory_map;
+} polars_ipc_read_options_t;
+
+/**
+ * Options used by `polars_dataframe_read_csv`, string arrays are given as an array of pointers
+ * along with an array of lengths. Use `polars_csv_read_options_default` to get the default
+ * options.
+ */
+typedef struct polars_csv_read_options_t {
+  uint8_t separator;
+  bool has_header;
+  /**
+   * The quote character, 0 disables quoting.
+   */
+  uint8_t quote_char;
+  /**
+   * Lines starting with this character are skipped, 0 disables comments.
+   */
+  uint8_t comment_char;
+  uintptr_t skip_rows;
+  /**
+   * Values which are interpreted as null in all columns.
+   */
+  const uint8_t *const *null_values;
+  const uintptr_t *null_values_lens;
+  uintptr_t n_null_values;
+  /**
+   * Stop reading after this number of rows, `SIZE_MAX` reads all the rows.
+   */
+  uintptr_t n_rows;
+  /**
+   * Names of the columns to read, all columns are read when `n_columns` is 0.
+   */
+  const uint8_t *const *columns;
+  const uintptr_t *columns_lens;
+  uintptr_t n_columns;
+  /**
+   * Overrides the inferred dtypes of the named columns.
+   */
+  const uint8_t *const *dtypes_names;
+  const uintptr_t *dtypes_names_lens;
+  const enum polars_value_type_t *dtypes;
+  uintptr_t n_dtypes;
+  bool try_parse_dates;
+} polars_csv_read_options_t;
+
+/**
+ * Options used by `polars_dataframe_write_csv`, empty format strings use the polars defaults.
+ * Use `polars_csv_write_options_default` to get the default options.
+ */
+typedef struct polars_csv_write_options_t {
+  uint8_t separator;
+  bool has_header;
+  uint8_t quote_char;
+  enum polars_quote_style_t quote_style;
+  const uint8_t *date_format;
+  uintptr_t date_format_len;
+  const uint8_t *datetime_format;
+  uintptr_t datetime_format_len;
+  const uint8_t *time_format;
+  uintptr_t time_format_len;
+  /**
+   * Number of decimals written for floats, `SIZE_MAX` writes all of them.
+   */
+  uintptr_t float_precision;
+  /**
+   * Representation of null values, empty by default.
+   */
+  const uint8_t *null_value;
+  uintptr_t null_value_len;
+} polars_csv_write_options_t;
+
+/**
+ * Options used by `polars_lazy_frame_scan_parquet`, use `polars_parquet_scan_options_default`
+ * to get the default options.
+ */
+typedef struct polars_parquet_scan_options_t {
+  /**
+   * Stop reading after this number of rows, `SIZE_MAX` reads all the rows.
+   */
+  uintptr_t n_rows;
+  /**
+   * Name of the row count column, no row count column is added when empty.
+   */
+  const uint8_t *row_count_name;
+  uintptr_t row_count_name_len;
+  uint32_t row_count_offset;
+  enum polars_parallel_strategy_t parallel;
+  bool low_memory;
+  /**
+   * Whether to add the `key=value` directories of the scanned files below the non-glob prefix
+   * of their path as columns.
+   */
+  bool hive_partitioning;
+} polars_parquet_scan_options_t;
+
+/**
+ * Options used by `polars_lazy_frame_scan_csv`, use `polars_csv_scan_options_default` to get
+ * the default options.
+ */
+typedef struct polars_csv_scan_options_t {
+  uint8_t separator;
+  bool has_header;
+  /**
+   * The quote character, 0 disables quoting.
+   */
+  uint8_t quote_char;
+  /**
+   * Lines starting with this character are skipped, 0 disables comments.
+   */
+  uint8_t comment_char;
+  uintptr_t skip_rows;
+  /**
+   * Stop reading after this number of rows, `SIZE_MAX` reads all the rows.
+   */
+  uintptr_t n_rows;
+  /**
+   * Number of rows used to infer the schema, `SIZE_MAX` uses the whole file.
+   */
+  uintptr_t infer_schema_length;
+  /**
+   * Overrides the inferred dtypes of the named columns.
+   */
+  const uint8_t *const *dtypes_names;
+  const uintptr_t *dtypes_names_lens;
+  const enum polars_value_type_t *dtypes;
+  uintptr_t n_dtypes;
+  enum polars_csv_encoding_t encoding;
+  bool ignore_errors;
+  bool low_memory;
+  /**
+   * Name of the row count column, no row count column is added when empty.
+   */
+  const uint8_t *row_count_name;
+  uintptr_t row_count_name_len;
+  uint32_t row_count_offset;
+  /**
+   * Whether to add the `key=value` directories of the scanned files below the non-glob prefix
+   * of their path as columns.
+   */
+  bool hive_partitioning;
+} polars_csv_scan_options_t;
+
+/**
+ * Options used by `polars_lazy_frame_scan_ndjson`, use `polars_ndjson_scan_options_default` to
+ * get the default options.
+ */
+typedef struct polars_ndjson_scan_options_t {
+  /**
+   * Stop reading after this number of rows, `SIZE_MAX` reads all the rows.
+   */
+  uintptr_t n_rows;
+  /**
+   * Number of rows used to infer the schema, `SIZE_MAX` uses the whole file.
+   */
+  uintptr_t infer_schema_length;
+  bool low_memory;
+  /**
+   * Name of the row count column, no row count column is added when empty.
+   */
+  const uint8_t *row_count_name;
+  uintptr_t row_count_name_len;
+  uint32_t row_count_offset;
+  /**
+   * Whether to add the `key=value` directories of the scanned files below the non-glob prefix
+   * of their path as columns.
+   */
+  bool hive_partitioning;
+} polars_ndjson_scan_options_t;
+
 uintptr_t polars_version(const uint8_t **out);
 
 uintptr_t polars_error_message(const struct polars_error_t *err, const uint8_t **data);
 
+enum polars_error_kind_t polars_error_kind(const struct polars_error_t *err);
+
 void polars_error_destroy(const struct polars_error_t *err);
 
+/**
+ * Creates an empty DataFrame, it must be destroyed with `polars_dataframe_destroy`.
+ */
+struct polars_dataframe_t *polars_dataframe_new(void);
+
+/**
+ * Errors are ignored, prefer `polars_dataframe_size_checked` which reports them.
+ */
 void polars_dataframe_size(struct polars_dataframe_t *df, uintptr_t *rows, uintptr_t *cols);
 
+const struct polars_error_t *polars_dataframe_size_checked(struct polars_dataframe_t *df,
+                                                           uintptr_t *rows,
+                                                           uintptr_t *cols);
+
 /**
  * Creates a DataFrame from a series of ArrowArray and ArrowSchema compatible the arrow C-ABI.
  *
@@ -68,38 +458,260 @@ void polars_dataframe_size(struct polars_dataframe_t *df, uintptr_t *rows, uintp
 struct polars_dataframe_t *polars_dataframe_new_from_carrow(const ArrowSchema *cfield,
                                                             ArrowArray carray);
 
+/**
+ * Creates a DataFrame as `polars_dataframe_new_from_carrow` does, but reports the reason of
+ * failures and runs the checks of `validation` on the array before accepting it. The checks are
+ * skipped when `validation` is null. The sizes of the buffers are not part of the Arrow C Data
+ * interface, so buffers shorter than their array are not detected.
+ *
+ * # Safety
+ * The ownership of `carray` is transferred as in `polars_dataframe_new_from_carrow`, it is
+ * released when an error is returned.
+ */
+const struct polars_error_t *polars_dataframe_new_from_carrow_validated(const ArrowSchema *cfield,
+                                                                        ArrowArray carray,
+                                                                        const struct polars_carrow_validation_t *validation,
+                                                                        struct polars_dataframe_t **out);
+
+/**
+ * Creates a DataFrame from the record batches of an Arrow C stream, each batch must be a
+ * struct array whose fields are the columns and becomes a chunk of the DataFrame. The stream
+ * is consumed and released by this function.
+ */
+const struct polars_error_t *polars_dataframe_new_from_carrow_stream(ArrowArrayStream *stream,
+                                                                     struct polars_dataframe_t **out);
+
 /**
  * Returns a ArrowSchema describing the dataframe's schema according to Arrow C Data interface.
  */
 ArrowSchema polars_dataframe_schema(struct polars_dataframe_t *df);
 
+/**
+ * Exports the DataFrame to `out` according to the Arrow C Stream interface, each chunk of the
+ * dataframe is a struct array whose fields are the columns. The chunks are not copied.
+ */
+const struct polars_error_t *polars_dataframe_export_carrow_stream(struct polars_dataframe_t *df,
+                                                                   ArrowArrayStream *out);
+
 const struct polars_error_t *polars_dataframe_new_from_series(struct polars_series_t *const *series,
                                                               uintptr_t nseries,
                                                               struct polars_dataframe_t **out);
 
 void polars_dataframe_destroy(struct polars_dataframe_t *df);
 
+/**
+ * Returns a new handle sharing the columns of `df`, each handle must be destroyed.
+ */
+struct polars_dataframe_t *polars_dataframe_clone(struct polars_dataframe_t *df);
+
 const struct polars_error_t *polars_dataframe_write_parquet(struct polars_dataframe_t *df,
                                                             const void *user,
                                                             IOCallback callback);
 
+struct polars_parquet_write_options_t polars_parquet_write_options_default(void);
+
+const struct polars_error_t *polars_dataframe_write_parquet_with_options(struct polars_dataframe_t *df,
+                                                                         const struct polars_parquet_write_options_t *options,
+                                                                         const void *user,
+                                                                         IOCallback callback);
+
+/**
+ * Writes the DataFrame as a hive-partitioned dataset under the directory `root`, with one
+ * `key=value/` directory level per column in `partition_by` and one parquet file per partition.
+ * Null partition values are written as `__HIVE_DEFAULT_PARTITION__`.
+ */
+const struct polars_error_t *polars_dataframe_write_parquet_partitioned(struct polars_dataframe_t *df,
+                                                                        const uint8_t *root,
+                                                                        uintptr_t rootlen,
+                                                                        const uint8_t *const *partition_by,
+                                                                        const uintptr_t *partition_by_lens,
+                                                                        uintptr_t n_partition_by,
+                                                                        const struct polars_parquet_write_options_t *options);
+
+struct polars_parquet_read_options_t polars_parquet_read_options_default(void);
+
 const struct polars_error_t *polars_dataframe_read_parquet(const uint8_t *path,
                                                            uintptr_t pathlen,
                                                            struct polars_dataframe_t **out);
 
+const struct polars_error_t *polars_dataframe_read_parquet_with_options(const uint8_t *path,
+                                                                        uintptr_t pathlen,
+                                                                        const struct polars_parquet_read_options_t *options,
+                                                                        struct polars_dataframe_t **out);
+
+const struct polars_error_t *polars_dataframe_read_parquet_from_callbacks(struct polars_reader_callbacks_t callbacks,
+                                                                          const struct polars_parquet_read_options_t *options,
+                                                                          struct polars_dataframe_t **out);
+
+const struct polars_error_t *polars_dataframe_write_ipc(struct polars_dataframe_t *df,
+                                                        enum polars_ipc_compression_t compression,
+                                                        const void *user,
+                                                        IOCallback callback);
+
+struct polars_ipc_read_options_t polars_ipc_read_options_default(void);
+
+const struct polars_error_t *polars_dataframe_read_ipc(const uint8_t *path,
+                                                       uintptr_t pathlen,
+                                                       const struct polars_ipc_read_options_t *options,
+                                                       struct polars_dataframe_t **out);
+
+/**
+ * Reads an IPC file through the provided callbacks, memory-mapping is not available in this
+ * case and the `memory_map` option is ignored.
+ */
+const struct polars_error_t *polars_dataframe_read_ipc_from_callbacks(struct polars_reader_callbacks_t callbacks,
+                                                                      const struct polars_ipc_read_options_t *options,
+                                                                      struct polars_dataframe_t **out);
+
+/**
+ * Reads a JSON file containing a single array of objects, one per row.
+ */
+const struct polars_error_t *polars_dataframe_read_json(const uint8_t *path,
+                                                        uintptr_t pathlen,
+                                                        struct polars_dataframe_t **out);
+
+const struct polars_error_t *polars_dataframe_read_json_from_callbacks(struct polars_reader_callbacks_t callbacks,
+                                                                       struct polars_dataframe_t **out);
+
+/**
+ * Reads a newline-delimited JSON file containing one object per line.
+ */
+const struct polars_error_t *polars_dataframe_read_ndjson(const uint8_t *path,
+                                                          uintptr_t pathlen,
+                                                          struct polars_dataframe_t **out);
+
+const struct polars_error_t *polars_dataframe_read_ndjson_from_callbacks(struct polars_reader_callbacks_t callbacks,
+                                                                         struct polars_dataframe_t **out);
+
+/**
+ * Writes the dataframe as a single JSON array containing one object per row.
+ */
+const struct polars_error_t *polars_dataframe_write_json(struct polars_dataframe_t *df,
+                                                         const void *user,
+                                                         IOCallback callback);
+
+/**
+ * Writes the dataframe as newline-delimited JSON with one object per line.
+ */
+const struct polars_error_t *polars_dataframe_write_ndjson(struct polars_dataframe_t *df,
+                                                           const void *user,
+                                                           IOCallback callback);
+
+struct polars_csv_read_options_t polars_csv_read_options_default(void);
+
+const struct polars_error_t *polars_dataframe_read_csv(const uint8_t *path,
+                                                       uintptr_t pathlen,
+                                                       const struct polars_csv_read_options_t *options,
+                                                       struct polars_dataframe_t **out);
+
+const struct polars_error_t *polars_dataframe_read_csv_from_callbacks(struct polars_reader_callbacks_t callbacks,
+                                                                      const struct polars_csv_read_options_t *options,
+                                                                      struct polars_dataframe_t **out);
+
+struct polars_csv_write_options_t polars_csv_write_options_default(void);
+
+const struct polars_error_t *polars_dataframe_write_csv(struct polars_dataframe_t *df,
+                                                        const struct polars_csv_write_options_t *options,
+                                                        const void *user,
+                                                        IOCallback callback);
+
+/**
+ * Writes the dataframe as a table to the callback, callback failures are ignored. Prefer
+ * `polars_dataframe_show_checked` which returns them.
+ */
 void polars_dataframe_show(struct polars_dataframe_t *df, const void *user, IOCallback callback);
 
+const struct polars_error_t *polars_dataframe_show_checked(struct polars_dataframe_t *df,
+                                                           const void *user,
+                                                           IOCallback callback);
+
 const struct polars_error_t *polars_dataframe_get(struct polars_dataframe_t *df,
                                                   const uint8_t *name,
                                                   uintptr_t len,
                                                   struct polars_series_t **out);
 
+/**
+ * Returns null on error, prefer `polars_dataframe_lazy_checked` which reports it.
+ */
 struct polars_lazy_frame_t *polars_dataframe_lazy(struct polars_dataframe_t *df);
 
+const struct polars_error_t *polars_dataframe_lazy_checked(struct polars_dataframe_t *df,
+                                                           struct polars_lazy_frame_t **out);
+
+struct polars_parquet_scan_options_t polars_parquet_scan_options_default(void);
+
+/**
+ * Creates a LazyFrame scanning the parquet file at `path`, the path can also be a glob pattern
+ * in which case all the matching files are scanned.
+ */
+const struct polars_error_t *polars_lazy_frame_scan_parquet(const uint8_t *path,
+                                                            uintptr_t pathlen,
+                                                            const struct polars_parquet_scan_options_t *options,
+                                                            struct polars_lazy_frame_t **out);
+
+/**
+ * Creates a LazyFrame scanning the union of the parquet files in `paths`, each path can also be
+ * a glob pattern. When `hive_partitioning` is set in the options, the `key=value` directories of
+ * each file below the non-glob prefix of its pattern are added as typed columns, and the files
+ * whose partition values do not match the filters applied to the returned LazyFrame are
+ * skipped.
+ */
+const struct polars_error_t *polars_lazy_frame_scan_parquet_files(const uint8_t *const *paths,
+                                                                  const uintptr_t *pathlens,
+                                                                  uintptr_t npaths,
+                                                                  const struct polars_parquet_scan_options_t *options,
+                                                                  struct polars_lazy_frame_t **out);
+
+struct polars_csv_scan_options_t polars_csv_scan_options_default(void);
+
+/**
+ * Creates a LazyFrame scanning the csv file at `path`, the path can also be a glob pattern
+ * in which case all the matching files are scanned.
+ */
+const struct polars_error_t *polars_lazy_frame_scan_csv(const uint8_t *path,
+                                                        uintptr_t pathlen,
+                                                        const struct polars_csv_scan_options_t *options,
+                                                        struct polars_lazy_frame_t **out);
+
+/**
+ * Creates a LazyFrame scanning the union of the csv files in `paths`, the paths and the
+ * hive partitions are handled as in `polars_lazy_frame_scan_parquet_files`.
+ */
+const struct polars_error_t *polars_lazy_frame_scan_csv_files(const uint8_t *const *paths,
+                                                              const uintptr_t *pathlens,
+                                                              uintptr_t npaths,
+                                                              const struct polars_csv_scan_options_t *options,
+                                                              struct polars_lazy_frame_t **out);
+
+struct polars_ndjson_scan_options_t polars_ndjson_scan_options_default(void);
+
+/**
+ * Creates a LazyFrame scanning the newline-delimited JSON file at `path`, the path can also be a
+ * glob pattern in which case all the matching files are scanned.
+ */
+const struct polars_error_t *polars_lazy_frame_scan_ndjson(const uint8_t *path,
+                                                           uintptr_t pathlen,
+                                                           const struct polars_ndjson_scan_options_t *options,
+                                                           struct polars_lazy_frame_t **out);
+
+/**
+ * Creates a LazyFrame scanning the union of the newline-delimited JSON files in `paths`, the
+ * paths and the hive partitions are handled as in `polars_lazy_frame_scan_parquet_files`.
+ */
+const struct polars_error_t *polars_lazy_frame_scan_ndjson_files(const uint8_t *const *paths,
+                                                                 const uintptr_t *pathlens,
+                                                                 uintptr_t npaths,
+                                                                 const struct polars_ndjson_scan_options_t *options,
+                                                                 struct polars_lazy_frame_t **out);
+
 void polars_lazy_frame_destroy(struct polars_lazy_frame_t *df);
 
 struct polars_lazy_frame_t *polars_lazy_frame_clone(struct polars_lazy_frame_t *df);
 
+/**
+ * Sorts the LazyFrame in place, errors are ignored. Prefer `polars_lazy_frame_sort_checked`
+ * which reports them.
+ */
 void polars_lazy_frame_sort(struct polars_lazy_frame_t *df,
                             const struct polars_expr_t *const *exprs,
                             uintptr_t nexprs,
@@ -107,27 +719,93 @@ void polars_lazy_frame_sort(struct polars_lazy_frame_t *df,
                             bool nulls_last,
                             bool maintain_order);
 
+/**
+ * Sorts the LazyFrame in place by `exprs`, `descending` has one flag per expression.
+ */
+const struct polars_error_t *polars_lazy_frame_sort_checked(struct polars_lazy_frame_t *df,
+                                                            const struct polars_expr_t *const *exprs,
+                                                            uintptr_t nexprs,
+                                                            const bool *descending,
+                                                            bool nulls_last,
+                                                            bool maintain_order);
+
 const struct polars_error_t *polars_lazy_frame_concat(struct polars_lazy_frame_t *const *lfs,
                                                       uintptr_t n,
                                                       struct polars_lazy_frame_t **out);
 
+/**
+ * Errors are ignored, prefer `polars_lazy_frame_with_columns_checked` which reports them.
+ */
 void polars_lazy_frame_with_columns(struct polars_lazy_frame_t *df,
                                     const struct polars_expr_t *const *exprs,
                                     uintptr_t nexprs);
 
+const struct polars_error_t *polars_lazy_frame_with_columns_checked(struct polars_lazy_frame_t *df,
+                                                                    const struct polars_expr_t *const *exprs,
+                                                                    uintptr_t nexprs);
+
+/**
+ * Errors are ignored, prefer `polars_lazy_frame_select_checked` which reports them.
+ */
 void polars_lazy_frame_select(struct polars_lazy_frame_t *df,
                               const struct polars_expr_t *const *exprs,
                               uintptr_t nexprs);
 
+const struct polars_error_t *polars_lazy_frame_select_checked(struct polars_lazy_frame_t *df,
+                                                              const struct polars_expr_t *const *exprs,
+                                                              uintptr_t nexprs);
+
+/**
+ * Errors are ignored, prefer `polars_lazy_frame_filter_checked` which reports them.
+ */
 void polars_lazy_frame_filter(struct polars_lazy_frame_t *df, const struct polars_expr_t *expr);
 
+const struct polars_error_t *polars_lazy_frame_filter_checked(struct polars_lazy_frame_t *df,
+                                                              const struct polars_expr_t *expr);
+
 const struct polars_error_t *polars_lazy_frame_collect(struct polars_lazy_frame_t *df,
                                                        struct polars_dataframe_t **out);
 
+/**
+ * Collects the LazyFrame and exports the result to `out` in the same way as
+ * `polars_dataframe_export_carrow_stream`.
+ */
+const struct polars_error_t *polars_lazy_frame_collect_carrow_stream(struct polars_lazy_frame_t *df,
+                                                                     ArrowArrayStream *out);
+
+/**
+ * Runs the query on the streaming engine and writes the result to the parquet file at `path`
+ * without materializing it in memory. Fails if the query cannot be run in a streaming fashion.
+ */
+const struct polars_error_t *polars_lazy_frame_sink_parquet(struct polars_lazy_frame_t *df,
+                                                            const uint8_t *path,
+                                                            uintptr_t pathlen,
+                                                            const struct polars_parquet_write_options_t *options);
+
+/**
+ * Runs the query on the streaming engine and writes the result to the IPC file at `path`
+ * without materializing it in memory. Fails if the query cannot be run in a streaming fashion.
+ */
+const struct polars_error_t *polars_lazy_frame_sink_ipc(struct polars_lazy_frame_t *df,
+                                                        const uint8_t *path,
+                                                        uintptr_t pathlen,
+                                                        enum polars_ipc_compression_t compression);
+
+/**
+ * Returns null on error, prefer `polars_lazy_frame_group_by_checked` which reports it.
+ */
 struct polars_lazy_group_by_t *polars_lazy_frame_group_by(struct polars_lazy_frame_t *df,
                                                           const struct polars_expr_t *const *exprs,
                                                           uintptr_t nexprs);
 
+const struct polars_error_t *polars_lazy_frame_group_by_checked(struct polars_lazy_frame_t *df,
+                                                                const struct polars_expr_t *const *exprs,
+                                                                uintptr_t nexprs,
+                                                                struct polars_lazy_group_by_t **out);
+
+/**
+ * Returns null on error, prefer `polars_lazy_frame_join_inner_checked` which reports it.
+ */
 struct polars_lazy_frame_t *polars_lazy_frame_join_inner(struct polars_lazy_frame_t *a,
                                                          struct polars_lazy_frame_t *b,
                                                          const struct polars_expr_t *const *exprs_a,
@@ -135,18 +813,65 @@ struct polars_lazy_frame_t *polars_lazy_frame_join_inner(struct polars_lazy_fram
                                                          const struct polars_expr_t *const *exprs_b,
                                                          uintptr_t exprs_b_len);
 
+const struct polars_error_t *polars_lazy_frame_join_inner_checked(struct polars_lazy_frame_t *a,
+                                                                  struct polars_lazy_frame_t *b,
+                                                                  const struct polars_expr_t *const *exprs_a,
+                                                                  uintptr_t exprs_a_len,
+                                                                  const struct polars_expr_t *const *exprs_b,
+                                                                  uintptr_t exprs_b_len,
+                                                                  struct polars_lazy_frame_t **out);
+
 const struct polars_error_t *polars_lazy_frame_fetch(struct polars_lazy_frame_t *df,
                                                      uintptr_t n,
                                                      struct polars_dataframe_t **out);
 
 void polars_lazy_group_by_destroy(const struct polars_lazy_group_by_t *gb);
 
+/**
+ * Returns null on error, prefer `polars_lazy_group_by_agg_checked` which reports it.
+ */
 struct polars_lazy_frame_t *polars_lazy_group_by_agg(struct polars_lazy_group_by_t *gb,
                                                      const struct polars_expr_t *const *exprs,
                                                      uintptr_t nexprs);
 
+const struct polars_error_t *polars_lazy_group_by_agg_checked(struct polars_lazy_group_by_t *gb,
+                                                              const struct polars_expr_t *const *exprs,
+                                                              uintptr_t nexprs,
+                                                              struct polars_lazy_frame_t **out);
+
+/**
+ * Opens the CSV file at `path` for reading in batches, the schema is inferred when opening the
+ * reader unless overridden by the options.
+ */
+const struct polars_error_t *polars_csv_batched_reader_open(const uint8_t *path,
+                                                            uintptr_t pathlen,
+                                                            const struct polars_csv_read_options_t *options,
+                                                            struct polars_csv_batched_reader_t **out);
+
+const struct polars_error_t *polars_csv_batched_reader_open_from_callbacks(struct polars_reader_callbacks_t callbacks,
+                                                                           const struct polars_csv_read_options_t *options,
+                                                                           struct polars_csv_batched_reader_t **out);
+
+void polars_csv_batched_reader_destroy(struct polars_csv_batched_reader_t *reader);
+
+/**
+ * Reads up to `n` DataFrames from the file and writes them to `out`, which must have room for
+ * `n` handles. `out_len` is set to the number of DataFrames written, it is 0 once the file is
+ * exhausted.
+ */
+const struct polars_error_t *polars_csv_batched_reader_next_batches(struct polars_csv_batched_reader_t *reader,
+                                                                    uintptr_t n,
+                                                                    struct polars_dataframe_t **out,
+                                                                    uintptr_t *out_len);
+
 void polars_expr_destroy(const struct polars_expr_t *expr);
 
+/**
+ * Returns a copy of `expr`, the whole expression tree is copied so the cost grows with its size.
+ * Both expressions are owned by the caller and must be destroyed with `polars_expr_destroy`.
+ */
+const struct polars_expr_t *polars_expr_clone(const struct polars_expr_t *expr);
+
 const struct polars_expr_t *polars_expr_literal_bool(bool value);
 
 const struct polars_expr_t *polars_expr_literal_null(void);
@@ -189,6 +914,13 @@ const struct polars_error_t *polars_expr_suffix(const struct polars_expr_t *expr
 const struct polars_expr_t *polars_expr_cast(const struct polars_expr_t *expr,
                                              enum polars_value_type_t dtype);
 
+/**
+ * Casts the expression to `dtype`, which cannot be a list or a struct type.
+ */
+const struct polars_error_t *polars_expr_cast_checked(const struct polars_expr_t *expr,
+                                                      enum polars_value_type_t dtype,
+                                                      const struct polars_expr_t **out);
+
 const struct polars_expr_t *polars_expr_keep_name(const struct polars_expr_t *expr);
 
 const struct polars_expr_t *polars_expr_sum(const struct polars_expr_t *expr);
@@ -348,20 +1080,151 @@ const struct polars_expr_t *polars_expr_str_ends_with(const struct polars_expr_t
 const struct polars_expr_t *polars_expr_str_contains_literal(const struct polars_expr_t *a,
                                                              const struct polars_expr_t *b);
 
+/**
+ * Returns null when `name` is not valid UTF-8, prefer `polars_expr_struct_field_by_name_checked`
+ * which reports the error.
+ */
 const struct polars_expr_t *polars_expr_struct_field_by_name(const struct polars_expr_t *a,
                                                              const uint8_t *name,
                                                              uintptr_t len);
 
+const struct polars_error_t *polars_expr_struct_field_by_name_checked(const struct polars_expr_t *a,
+                                                                      const uint8_t *name,
+                                                                      uintptr_t len,
+                                                                      const struct polars_expr_t **out);
+
 const struct polars_expr_t *polars_expr_struct_field_by_index(const struct polars_expr_t *a,
                                                               int64_t fieldidx);
 
+/**
+ * Returns the field at `fieldidx` of the struct expression, negative indices count from the
+ * last field.
+ */
+const struct polars_error_t *polars_expr_struct_field_by_index_checked(const struct polars_expr_t *a,
+                                                                       int64_t fieldidx,
+                                                                       const struct polars_expr_t **out);
+
+/**
+ * Returns null when one of the names is not valid UTF-8, prefer
+ * `polars_expr_struct_rename_fields_checked` which reports the error.
+ */
 const struct polars_expr_t *polars_expr_struct_rename_fields(const struct polars_expr_t *a,
                                                              const uint8_t *const *names,
                                                              const uintptr_t *lens,
                                                              uintptr_t num_names);
 
+const struct polars_error_t *polars_expr_struct_rename_fields_checked(const struct polars_expr_t *a,
+                                                                      const uint8_t *const *names,
+                                                                      const uintptr_t *lens,
+                                                                      uintptr_t num_names,
+                                                                      const struct polars_expr_t **out);
+
+/**
+ * Reads the metadata of the parquet file at `path` without reading its data.
+ */
+const struct polars_error_t *polars_parquet_metadata_read(const uint8_t *path,
+                                                          uintptr_t pathlen,
+                                                          struct polars_parquet_metadata_t **out);
+
+const struct polars_error_t *polars_parquet_metadata_read_from_callbacks(struct polars_reader_callbacks_t callbacks,
+                                                                         struct polars_parquet_metadata_t **out);
+
+void polars_parquet_metadata_destroy(struct polars_parquet_metadata_t *metadata);
+
+uintptr_t polars_parquet_metadata_num_rows(struct polars_parquet_metadata_t *metadata);
+
+uintptr_t polars_parquet_metadata_num_row_groups(struct polars_parquet_metadata_t *metadata);
+
+const struct polars_error_t *polars_parquet_metadata_row_group_num_rows(struct polars_parquet_metadata_t *metadata,
+                                                                        uintptr_t index,
+                                                                        uintptr_t *out);
+
+/**
+ * Returns the total uncompressed size in bytes of the row group at `index`.
+ */
+const struct polars_error_t *polars_parquet_metadata_row_group_byte_size(struct polars_parquet_metadata_t *metadata,
+                                                                         uintptr_t index,
+                                                                         uintptr_t *out);
+
+/**
+ * Writes an ArrowSchema describing the file's schema according to Arrow C Data interface,
+ * the schema is a struct whose fields are the columns as in `polars_dataframe_schema`.
+ */
+const struct polars_error_t *polars_parquet_metadata_schema(struct polars_parquet_metadata_t *metadata,
+                                                            ArrowSchema *out);
+
+uintptr_t polars_parquet_metadata_num_key_values(struct polars_parquet_metadata_t *metadata);
+
+/**
+ * Returns the key and value of the key-value metadata entry at `index`, the value is set to
+ * null with a length of 0 when the entry has no value. The returned strings are owned by the
+ * metadata.
+ */
+const struct polars_error_t *polars_parquet_metadata_key_value(struct polars_parquet_metadata_t *metadata,
+                                                               uintptr_t index,
+                                                               const uint8_t **key,
+                                                               uintptr_t *keylen,
+                                                               const uint8_t **value,
+                                                               uintptr_t *valuelen);
+
+/**
+ * Returns the statistics of the column `name` as a DataFrame with the columns `null_count`,
+ * `distinct_count`, `min` and `max` and one row per row group. Values are null when the
+ * statistic was not written.
+ */
+const struct polars_error_t *polars_parquet_metadata_statistics(struct polars_parquet_metadata_t *metadata,
+                                                                const uint8_t *name,
+                                                                uintptr_t len,
+                                                                struct polars_dataframe_t **out);
+
+/**
+ * Opens a parquet writer writing to `callback`, the file schema is given by `schema` which
+ * describes a struct whose fields are the columns as returned by `polars_dataframe_schema`.
+ */
+const struct polars_error_t *polars_parquet_batched_writer_open(const ArrowSchema *schema,
+                                                                const struct polars_parquet_write_options_t *options,
+                                                                const void *user,
+                                                                IOCallback callback,
+                                                                struct polars_parquet_batched_writer_t **out);
+
+/**
+ * Writes the rows of `df` to the file, `df` must have the schema the writer was opened with.
+ */
+const struct polars_error_t *polars_parquet_batched_writer_write_batch(struct polars_parquet_batched_writer_t *writer,
+                                                                       struct polars_dataframe_t *df);
+
+/**
+ * Writes the footer of the file, no batch can be written afterwards. The writer must still be
+ * destroyed with `polars_parquet_batched_writer_destroy`.
+ */
+const struct polars_error_t *polars_parquet_batched_writer_finish(struct polars_parquet_batched_writer_t *writer);
+
+/**
+ * Destroys the writer, the footer is not written if `polars_parquet_batched_writer_finish` was
+ * not called.
+ */
+void polars_parquet_batched_writer_destroy(struct polars_parquet_batched_writer_t *writer);
+
+/**
+ * Creates a series named `name` from an array according to the Arrow C Data interface.
+ *
+ * # Safety
+ * The ownership of `array` is transferred to the series as in `polars_dataframe_new_from_carrow`,
+ * the caller should *not* release it.
+ */
+const struct polars_error_t *polars_series_new_from_carrow(const uint8_t *name,
+                                                           uintptr_t namelen,
+                                                           const ArrowSchema *schema,
+                                                           ArrowArray array,
+                                                           struct polars_series_t **out);
+
 void polars_series_destroy(struct polars_series_t *series);
 
+/**
+ * Returns a new handle sharing the data of `series`, each handle must be destroyed.
+ */
+struct polars_series_t *polars_series_clone(struct polars_series_t *series);
+
 enum polars_value_type_t polars_series_type(struct polars_series_t *series);
 
 uintptr_t polars_series_length(struct polars_series_t *series);
@@ -370,16 +1233,39 @@ uintptr_t polars_series_null_count(struct polars_series_t *series);
 
 ArrowSchema polars_series_schema(struct polars_series_t *series);
 
+uintptr_t polars_series_num_chunks(struct polars_series_t *series);
+
+/**
+ * Exports the chunk at `index` to `out` according to the Arrow C Data interface, its type
+ * matches the field returned by `polars_series_schema`. The buffers are shared with the series
+ * and kept alive until the array is released by the caller.
+ */
+const struct polars_error_t *polars_series_export_carrow(struct polars_series_t *series,
+                                                         uintptr_t index,
+                                                         ArrowArray *out);
+
 /**
  * Returns whether or not the value at index `index` is null, return false if the index is out of
- * bounds.
+ * bounds. Prefer `polars_series_is_null_checked` which reports it.
  */
 bool polars_series_is_null(struct polars_series_t *series, uintptr_t index);
 
+const struct polars_error_t *polars_series_is_null_checked(struct polars_series_t *series,
+                                                           uintptr_t index,
+                                                           bool *out);
+
 uintptr_t polars_series_name(struct polars_series_t *series, const uint8_t **out);
 
+/**
+ * Returns the value at `index` or null on error, prefer `polars_series_get_checked` which
+ * reports it.
+ */
 const struct polars_value_t *polars_series_get(struct polars_series_t *series, uintptr_t index);
 
+const struct polars_error_t *polars_series_get_checked(struct polars_series_t *series,
+                                                       uintptr_t index,
+                                                       struct polars_value_t **out);
+
 const struct polars_error_t *polars_series_get_bool(struct polars_series_t *series,
                                                     uintptr_t index,
                                                     bool *out);
@@ -424,6 +1310,8 @@ const struct polars_error_t *polars_series_get_f64(struct polars_series_t *serie
                                                    uintptr_t index,
                                                    double *out);
 
+struct polars_carrow_validation_t polars_carrow_validation_default(void);
+
 enum polars_value_type_t polars_value_type(struct polars_value_t *value);
 
 void polars_value_destroy(struct polars_value_t *value);
@@ -466,15 +1354,17 @@ const struct polars_error_t *polars_value_binary_get(struct polars_value_t *valu
 
 /**
  * Used to get value of of a Struct value fields.
- *
- * NOTE: The value producing the new value must outlive the value from the field.
- *
- * Safety: Values lifetimes must be valid and only support physical dtypes for now.
  */
 const struct polars_error_t *polars_value_struct_get(struct polars_value_t *value,
                                                      uintptr_t fieldidx,
                                                      struct polars_value_t **out);
 
+/**
+ * Returns the time zone of a datetime value, the time zone is set to null with a length of 0 when
+ * the value has none. The returned string is owned by the value.
+ */
+uintptr_t polars_value_time_zone(struct polars_value_t *value, const uint8_t **out);
+
 /**
  * Returns the element type of the provided value which must be a list.
  * The value type is PolarsValueTypeUnknown if the value is not a list
@@ -0,0 +1,106 @@
+use polars::io::csv::read_impl::OwnedBatchedCsvReader;
+
+use crate::*;
+
+/// A CSV reader returning the file as a sequence of DataFrames, see
+/// `polars_csv_batched_reader_next_batches`.
+pub struct polars_csv_batched_reader_t {
+    inner: OwnedBatchedCsvReader,
+}
+
+unsafe fn make_batched_reader(
+    reader: Box<dyn MmapBytesReader>,
+    options: *const polars_csv_read_options_t,
+    out: *mut *mut polars_csv_batched_reader_t,
+) -> *const polars_error_t {
+    let reader = match make_csv_reader(reader, options) {
+        Ok(reader) => reader,
+        Err(err) => return make_error(err),
+    };
+
+    match reader.batched_read(None) {
+        Ok(reader) => {
+            *out = Box::into_raw(Box::new(polars_csv_batched_reader_t { inner: reader }));
+        }
+        Err(err) => return make_error(err),
+    }
+
+    std::ptr::null()
+}
+
+/// Opens the CSV file at `path` for reading in batches, the schema is inferred when opening the
+/// reader unless overridden by the options.
+#[no_mangle]
+pub unsafe extern "C" fn polars_csv_batched_reader_open(
+    path: *const u8,
+    pathlen: usize,
+    options: *const polars_csv_read_options_t,
+    out: *mut *mut polars_csv_batched_reader_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        let path = std::slice::from_raw_parts(path, pathlen);
+        let path = match std::str::from_utf8(path) {
+            Ok(path) => path,
+            Err(err) => return make_error(err),
+        };
+
+        let file = match std::fs::OpenOptions::new().read(true).open(path) {
+            Ok(file) => file,
+            Err(err) => return make_error(err),
+        };
+
+        make_batched_reader(Box::new(file), options, out)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_csv_batched_reader_open_from_callbacks(
+    callbacks: polars_reader_callbacks_t,
+    options: *const polars_csv_read_options_t,
+    out: *mut *mut polars_csv_batched_reader_t,
+) -> *const polars_error_t {
+    catch_panic(|| make_batched_reader(Box::new(callbacks), options, out))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_csv_batched_reader_destroy(
+    reader: *mut polars_csv_batched_reader_t,
+) {
+    catch_panic(|| {
+        assert!(!reader.is_null());
+        let _ = Box::from_raw(reader);
+    })
+}
+
+/// Reads up to `n` DataFrames from the file and writes them to `out`, which must have room for
+/// `n` handles. `out_len` is set to the number of DataFrames written, it is 0 once the file is
+/// exhausted.
+#[no_mangle]
+pub unsafe extern "C" fn polars_csv_batched_reader_next_batches(
+    reader: *mut polars_csv_batched_reader_t,
+    n: usize,
+    out: *mut *mut polars_dataframe_t,
+    out_len: *mut usize,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!reader.is_null());
+        assert!(!out_len.is_null());
+        *out_len = 0;
+        if n == 0 {
+            return std::ptr::null();
+        }
+
+        let batches = match (*reader).inner.next_batches(n) {
+            Ok(batches) => batches.unwrap_or_default(),
+            Err(err) => return make_error(err),
+        };
+
+        let out = std::slice::from_raw_parts_mut(out, n);
+        for (slot, df) in out.iter_mut().zip(batches) {
+            *slot = make_dataframe(df);
+            *out_len += 1;
+        }
+
+        std::ptr::null()
+    })
+}
@@ -11,48 +11,60 @@ fn make_expr(expr: Expr) -> *const polars_expr_t {
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_expr_destroy(expr: *const polars_expr_t) {
-    assert!(!expr.is_null());
-    let _ = Box::from_raw(expr.cast_mut());
+    catch_panic(|| {
+        assert!(!expr.is_null());
+        let _ = Box::from_raw(expr.cast_mut());
+    })
+}
+
+/// Returns a copy of `expr`, the whole expression tree is copied so the cost grows with its size.
+/// Both expressions are owned by the caller and must be destroyed with `polars_expr_destroy`.
+#[no_mangle]
+pub unsafe extern "C" fn polars_expr_clone(expr: *const polars_expr_t) -> *const polars_expr_t {
+    catch_panic(|| {
+        assert!(!expr.is_null());
+        make_expr((*expr).inner.clone())
+    })
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_expr_literal_bool(value: bool) -> *const polars_expr_t {
-    make_expr(Expr::Literal(LiteralValue::Boolean(value)))
+    catch_panic(|| make_expr(Expr::Literal(LiteralValue::Boolean(value))))
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_expr_literal_null() -> *const polars_expr_t {
-    make_expr(Expr::Literal(LiteralValue::Null))
+    catch_panic(|| make_expr(Expr::Literal(LiteralValue::Null)))
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_expr_literal_i32(value: i32) -> *const polars_expr_t {
-    make_expr(Expr::Literal(LiteralValue::Int32(value)))
+    catch_panic(|| make_expr(Expr::Literal(LiteralValue::Int32(value))))
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_expr_literal_i64(value: i64) -> *const polars_expr_t {
-    make_expr(Expr::Literal(LiteralValue::Int64(value)))
+    catch_panic(|| make_expr(Expr::Literal(LiteralValue::Int64(value))))
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_expr_literal_u32(value: u32) -> *const polars_expr_t {
-    make_expr(Expr::Literal(LiteralValue::UInt32(value)))
+    catch_panic(|| make_expr(Expr::Literal(LiteralValue::UInt32(value))))
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_expr_literal_u64(value: u64) -> *const polars_expr_t {
-    make_expr(Expr::Literal(LiteralValue::UInt64(value)))
+    catch_panic(|| make_expr(Expr::Literal(LiteralValue::UInt64(value))))
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_expr_literal_f32(value: f32) -> *const polars_expr_t {
-    make_expr(Expr::Literal(LiteralValue::Float32(value)))
+    catch_panic(|| make_expr(Expr::Literal(LiteralValue::Float32(value))))
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_expr_literal_f64(value: f64) -> *const polars_expr_t {
-    make_expr(Expr::Literal(LiteralValue::Float64(value)))
+    catch_panic(|| make_expr(Expr::Literal(LiteralValue::Float64(value))))
 }
 
 #[no_mangle]
@@ -61,12 +73,14 @@ pub unsafe extern "C" fn polars_expr_literal_utf8(
     len: usize,
     out: *mut *const polars_expr_t,
 ) -> *const polars_error_t {
-    let value = match std::str::from_utf8(std::slice::from_raw_parts(s, len)) {
-        Ok(value) => value,
-        Err(err) => return make_error(err),
-    };
-    *out = make_expr(Expr::Literal(LiteralValue::Utf8(value.to_owned())));
-    std::ptr::null()
+    catch_panic(|| {
+        let value = match std::str::from_utf8(std::slice::from_raw_parts(s, len)) {
+            Ok(value) => value,
+            Err(err) => return make_error(err),
+        };
+        *out = make_expr(Expr::Literal(LiteralValue::Utf8(value.to_owned())));
+        std::ptr::null()
+    })
 }
 
 #[no_mangle]
@@ -75,13 +89,15 @@ pub unsafe extern "C" fn polars_expr_col(
     len: usize,
     out: *mut *const polars_expr_t,
 ) -> *const polars_error_t {
-    let name = match std::str::from_utf8(std::slice::from_raw_parts(name, len)) {
-        Ok(value) => value,
-        Err(err) => return make_error(err),
-    };
-    let expr = col(name);
-    *out = make_expr(expr);
-    std::ptr::null()
+    catch_panic(|| {
+        let name = match std::str::from_utf8(std::slice::from_raw_parts(name, len)) {
+            Ok(value) => value,
+            Err(err) => return make_error(err),
+        };
+        let expr = col(name);
+        *out = make_expr(expr);
+        std::ptr::null()
+    })
 }
 
 #[no_mangle]
@@ -91,13 +107,15 @@ pub unsafe extern "C" fn polars_expr_alias(
     len: usize,
     out: *mut *const polars_expr_t,
 ) -> *const polars_error_t {
-    let name = match std::str::from_utf8(std::slice::from_raw_parts(name, len)) {
-        Ok(value) => value,
-        Err(err) => return make_error(err),
-    };
-    let aliased = (*expr).inner.clone().alias(name);
-    *out = make_expr(aliased);
-    std::ptr::null()
+    catch_panic(|| {
+        let name = match std::str::from_utf8(std::slice::from_raw_parts(name, len)) {
+            Ok(value) => value,
+            Err(err) => return make_error(err),
+        };
+        let aliased = (*expr).inner.clone().alias(name);
+        *out = make_expr(aliased);
+        std::ptr::null()
+    })
 }
 
 #[no_mangle]
@@ -107,13 +125,15 @@ pub unsafe extern "C" fn polars_expr_prefix(
     len: usize,
     out: *mut *const polars_expr_t,
 ) -> *const polars_error_t {
-    let name = match std::str::from_utf8(std::slice::from_raw_parts(name, len)) {
-        Ok(value) => value,
-        Err(err) => return make_error(err),
-    };
-    let aliased = (*expr).inner.clone().prefix(name);
-    *out = make_expr(aliased);
-    std::ptr::null()
+    catch_panic(|| {
+        let name = match std::str::from_utf8(std::slice::from_raw_parts(name, len)) {
+            Ok(value) => value,
+            Err(err) => return make_error(err),
+        };
+        let aliased = (*expr).inner.clone().prefix(name);
+        *out = make_expr(aliased);
+        std::ptr::null()
+    })
 }
 
 #[no_mangle]
@@ -123,13 +143,15 @@ pub unsafe extern "C" fn polars_expr_suffix(
     len: usize,
     out: *mut *const polars_expr_t,
 ) -> *const polars_error_t {
-    let name = match std::str::from_utf8(std::slice::from_raw_parts(name, len)) {
-        Ok(value) => value,
-        Err(err) => return make_error(err),
-    };
-    let aliased = (*expr).inner.clone().suffix(name);
-    *out = make_expr(aliased);
-    std::ptr::null()
+    catch_panic(|| {
+        let name = match std::str::from_utf8(std::slice::from_raw_parts(name, len)) {
+            Ok(value) => value,
+            Err(err) => return make_error(err),
+        };
+        let aliased = (*expr).inner.clone().suffix(name);
+        *out = make_expr(aliased);
+        std::ptr::null()
+    })
 }
 
 #[no_mangle]
@@ -137,17 +159,44 @@ pub unsafe extern "C" fn polars_expr_cast(
     expr: *const polars_expr_t,
     dtype: polars_value_type_t,
 ) -> *const polars_expr_t {
-    let expr = (*expr).inner.clone();
-    make_expr(cast(expr, dtype.to_dtype()))
+    catch_panic(|| {
+        let expr = (*expr).inner.clone();
+        make_expr(cast(expr, dtype.to_dtype()))
+    })
+}
+
+/// Casts the expression to `dtype`, which cannot be a list or a struct type.
+#[no_mangle]
+pub unsafe extern "C" fn polars_expr_cast_checked(
+    expr: *const polars_expr_t,
+    dtype: polars_value_type_t,
+    out: *mut *const polars_expr_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!expr.is_null());
+        let dtype = match dtype {
+            polars_value_type_t::PolarsValueTypeList
+            | polars_value_type_t::PolarsValueTypeStruct => {
+                return make_error(polars_err!(
+                    InvalidOperation: "cannot cast to a list or struct type without its inner types"
+                ));
+            }
+            dtype => dtype.to_dtype(),
+        };
+        *out = make_expr(cast((*expr).inner.clone(), dtype));
+        std::ptr::null()
+    })
 }
 
 macro_rules! gen_impl_expr {
     ($n: ident, $t: expr) => {
         #[no_mangle]
         pub unsafe extern "C" fn $n(expr: *const polars_expr_t) -> *const polars_expr_t {
-            let expr = &(*expr).inner;
-            let out_expr = $t(expr.clone());
-            make_expr(out_expr)
+            catch_panic(|| {
+                let expr = &(*expr).inner;
+                let out_expr = $t(expr.clone());
+                make_expr(out_expr)
+            })
         }
     };
 }
@@ -202,10 +251,12 @@ macro_rules! gen_impl_expr_binary {
             a: *const polars_expr_t,
             b: *const polars_expr_t,
         ) -> *const polars_expr_t {
-            let a = &(*a).inner;
-            let b = &(*b).inner;
-            let out_expr = $t(a.clone(), b.clone());
-            make_expr(out_expr)
+            catch_panic(|| {
+                let a = &(*a).inner;
+                let b = &(*b).inner;
+                let out_expr = $t(a.clone(), b.clone());
+                make_expr(out_expr)
+            })
         }
     };
 }
@@ -227,8 +278,10 @@ macro_rules! gen_impl_expr_list {
     ($n: ident, $t: expr) => {
         #[no_mangle]
         pub unsafe extern "C" fn $n(a: *const polars_expr_t) -> *const polars_expr_t {
-            let expr = $t((*a).inner.clone().list());
-            make_expr(expr)
+            catch_panic(|| {
+                let expr = $t((*a).inner.clone().list());
+                make_expr(expr)
+            })
         }
     };
 }
@@ -253,8 +306,10 @@ macro_rules! gen_impl_expr_binary_list {
             a: *const polars_expr_t,
             b: *const polars_expr_t,
         ) -> *const polars_expr_t {
-            let expr = $t((*a).inner.clone().list(), ((*b).inner.clone()));
-            make_expr(expr)
+            catch_panic(|| {
+                let expr = $t((*a).inner.clone().list(), ((*b).inner.clone()));
+                make_expr(expr)
+            })
         }
     };
 }
@@ -267,8 +322,10 @@ macro_rules! gen_impl_expr_str {
     ($n: ident, $t: expr) => {
         #[no_mangle]
         pub unsafe extern "C" fn $n(a: *const polars_expr_t) -> *const polars_expr_t {
-            let expr = $t((*a).inner.clone().str());
-            make_expr(expr)
+            catch_panic(|| {
+                let expr = $t((*a).inner.clone().str());
+                make_expr(expr)
+            })
         }
     };
 }
@@ -288,8 +345,10 @@ macro_rules! gen_impl_expr_binary_str {
             a: *const polars_expr_t,
             b: *const polars_expr_t,
         ) -> *const polars_expr_t {
-            let expr = $t((*a).inner.clone().str(), ((*b).inner.clone()));
-            make_expr(expr)
+            catch_panic(|| {
+                let expr = $t((*a).inner.clone().str(), ((*b).inner.clone()));
+                make_expr(expr)
+            })
         }
     };
 }
@@ -301,18 +360,40 @@ gen_impl_expr_binary_str!(
     StringNameSpace::contains_literal
 );
 
+/// Returns null when `name` is not valid UTF-8, prefer `polars_expr_struct_field_by_name_checked`
+/// which reports the error.
 #[no_mangle]
 pub unsafe extern "C" fn polars_expr_struct_field_by_name(
     a: *const polars_expr_t,
     name: *const u8,
     len: usize,
 ) -> *const polars_expr_t {
-    let name = std::slice::from_raw_parts(name, len);
-    let Ok(name) = std::str::from_utf8(name) else {
-        return std::ptr::null();
-    };
-    let expr = (*a).inner.clone().struct_().field_by_name(name);
-    make_expr(expr)
+    catch_panic(|| {
+        let name = std::slice::from_raw_parts(name, len);
+        let Ok(name) = std::str::from_utf8(name) else {
+            return std::ptr::null();
+        };
+        let expr = (*a).inner.clone().struct_().field_by_name(name);
+        make_expr(expr)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_expr_struct_field_by_name_checked(
+    a: *const polars_expr_t,
+    name: *const u8,
+    len: usize,
+    out: *mut *const polars_expr_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!a.is_null());
+        let name = match std::str::from_utf8(std::slice::from_raw_parts(name, len)) {
+            Ok(name) => name,
+            Err(err) => return make_error(err),
+        };
+        *out = make_expr((*a).inner.clone().struct_().field_by_name(name));
+        std::ptr::null()
+    })
 }
 
 #[no_mangle]
@@ -320,10 +401,29 @@ pub unsafe extern "C" fn polars_expr_struct_field_by_index(
     a: *const polars_expr_t,
     fieldidx: i64,
 ) -> *const polars_expr_t {
-    let expr = (*a).inner.clone().struct_().field_by_index(fieldidx);
-    make_expr(expr)
+    catch_panic(|| {
+        let expr = (*a).inner.clone().struct_().field_by_index(fieldidx);
+        make_expr(expr)
+    })
+}
+
+/// Returns the field at `fieldidx` of the struct expression, negative indices count from the
+/// last field.
+#[no_mangle]
+pub unsafe extern "C" fn polars_expr_struct_field_by_index_checked(
+    a: *const polars_expr_t,
+    fieldidx: i64,
+    out: *mut *const polars_expr_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!a.is_null());
+        *out = make_expr((*a).inner.clone().struct_().field_by_index(fieldidx));
+        std::ptr::null()
+    })
 }
 
+/// Returns null when one of the names is not valid UTF-8, prefer
+/// `polars_expr_struct_rename_fields_checked` which reports the error.
 #[no_mangle]
 pub unsafe extern "C" fn polars_expr_struct_rename_fields(
     a: *const polars_expr_t,
@@ -331,17 +431,30 @@ pub unsafe extern "C" fn polars_expr_struct_rename_fields(
     lens: *const usize,
     num_names: usize,
 ) -> *const polars_expr_t {
-    let names = std::slice::from_raw_parts(names, num_names);
-    let lens = std::slice::from_raw_parts(lens, num_names);
-
-    let names = names
-        .iter()
-        .zip(lens)
-        .map(|(name, len)| {
-            std::str::from_utf8_unchecked(std::slice::from_raw_parts(*name, *len)).to_owned()
-        })
-        .collect();
-
-    let expr = (*a).inner.clone().struct_().rename_fields(names);
-    make_expr(expr)
+    catch_panic(|| {
+        let Ok(names) = make_strings(names, lens, num_names) else {
+            return std::ptr::null();
+        };
+        let expr = (*a).inner.clone().struct_().rename_fields(names);
+        make_expr(expr)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_expr_struct_rename_fields_checked(
+    a: *const polars_expr_t,
+    names: *const *const u8,
+    lens: *const usize,
+    num_names: usize,
+    out: *mut *const polars_expr_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!a.is_null());
+        let names = match make_strings(names, lens, num_names) {
+            Ok(names) => names,
+            Err(err) => return make_error(err),
+        };
+        *out = make_expr((*a).inner.clone().struct_().rename_fields(names));
+        std::ptr::null()
+    })
 }
@@ -0,0 +1,420 @@
+//! Helpers for datasets laid out as hive partitions, i.e. `key=value/` directories.
+
+use std::{
+    any::Any,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use polars_core::{
+    export::chrono::{NaiveDate, NaiveDateTime},
+    utils::arrow::temporal_conversions::{date32_to_date, timestamp_us_to_datetime},
+};
+
+use crate::*;
+
+/// Value used by hive writers for null partition values.
+pub(crate) const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Returns the directories of `pattern` before its first component with a glob.
+fn glob_root(pattern: &str) -> PathBuf {
+    Path::new(pattern)
+        .components()
+        .take_while(|c| !c.as_os_str().to_string_lossy().contains(['*', '?', '[']))
+        .collect()
+}
+
+/// Expands the glob patterns in `paths`, paths without patterns are kept as is. Each file comes
+/// with its path relative to the directories of its pattern before the first glob, its partitions
+/// are only parsed from this relative path so paths without patterns have none.
+pub(crate) fn expand_paths(paths: &[String]) -> PolarsResult<Vec<(PathBuf, PathBuf)>> {
+    let mut out = Vec::with_capacity(paths.len());
+    for path in paths {
+        if !path.contains(['*', '?', '[']) {
+            out.push((PathBuf::from(path), PathBuf::new()));
+            continue;
+        }
+
+        let root = glob_root(path);
+        let matches = glob::glob(path)
+            .map_err(|err| polars_err!(ComputeError: "invalid glob pattern {}: {}", path, err))?;
+        let len = out.len();
+        for entry in matches {
+            let entry = entry.map_err(|err| polars_err!(ComputeError: "{}", err))?;
+            let relative = entry
+                .strip_prefix(&root)
+                .map(Path::to_path_buf)
+                .unwrap_or_default();
+            out.push((entry, relative));
+        }
+        polars_ensure!(out.len() > len, ComputeError: "no matching files found in {}", path);
+    }
+    Ok(out)
+}
+
+/// Decodes the `%XX` escapes used by hive writers for special characters.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+            if let Some(b) = hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                out.push(b);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Returns the `key=value` pairs from the directories of `path`, a path relative to the root of
+/// its dataset, from the outermost directory.
+fn parse_partitions(path: &Path) -> Vec<(String, Option<String>)> {
+    let Some(parent) = path.parent() else {
+        return Vec::new();
+    };
+    parent
+        .components()
+        .filter_map(|c| c.as_os_str().to_str())
+        .filter_map(|c| c.split_once('='))
+        .map(|(key, value)| {
+            let value = (value != HIVE_DEFAULT_PARTITION).then(|| percent_decode(value));
+            (percent_decode(key), value)
+        })
+        .collect()
+}
+
+/// Parses all the non-null `values` with `parse`, returns `None` if one of them cannot be parsed.
+fn parse_all<T>(
+    values: &[Option<&str>],
+    parse: impl Fn(&str) -> Option<T>,
+) -> Option<Vec<Option<T>>> {
+    values
+        .iter()
+        .map(|v| v.map_or(Some(None), |v| parse(v).map(Some)))
+        .collect()
+}
+
+/// Returns the number of days since the epoch of a `%Y-%m-%d` date.
+fn parse_date(value: &str) -> Option<i32> {
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?;
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+    Some((date - epoch).num_days() as i32)
+}
+
+/// Returns the number of microseconds since the epoch of a `%Y-%m-%d %H:%M:%S` datetime, the
+/// date and the time can also be separated by a `T` and the seconds can have a fraction.
+fn parse_datetime(value: &str) -> Option<i64> {
+    let datetime = ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())?;
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?.and_hms_opt(0, 0, 0)?;
+    (datetime - epoch).num_microseconds()
+}
+
+/// Builds a Series from the partition values of a key using the first dtype among Int64,
+/// Float64, Boolean, Date, Datetime and Utf8 which can represent all the values.
+fn make_partition_series(name: &str, values: &[Option<String>]) -> PolarsResult<Series> {
+    let values: Vec<Option<&str>> = values.iter().map(|v| v.as_deref()).collect();
+    if let Some(parsed) = parse_all(&values, |v| v.parse::<i64>().ok()) {
+        return Ok(Series::new(name, parsed));
+    }
+    if let Some(parsed) = parse_all(&values, |v| v.parse::<f64>().ok()) {
+        return Ok(Series::new(name, parsed));
+    }
+    if let Some(parsed) = parse_all(&values, |v| v.parse::<bool>().ok()) {
+        return Ok(Series::new(name, parsed));
+    }
+    if let Some(parsed) = parse_all(&values, parse_date) {
+        return Series::new(name, parsed).cast(&DataType::Date);
+    }
+    if let Some(parsed) = parse_all(&values, parse_datetime) {
+        return Series::new(name, parsed).cast(&DataType::Datetime(TimeUnit::Microseconds, None));
+    }
+    Ok(Series::new(name, values))
+}
+
+/// Returns a DataFrame with one row per file and one typed column per partition key, keys
+/// missing from a file path are null. The paths are relative to the roots of their datasets as
+/// returned by `expand_paths`.
+pub(crate) fn partitions_frame(paths: &[PathBuf]) -> PolarsResult<DataFrame> {
+    let partitions: Vec<_> = paths.iter().map(|p| parse_partitions(p)).collect();
+
+    let mut keys: Vec<&str> = Vec::new();
+    for (key, _) in partitions.iter().flatten() {
+        if !keys.contains(&key.as_str()) {
+            keys.push(key);
+        }
+    }
+
+    let columns = keys
+        .iter()
+        .map(|key| {
+            let values: Vec<Option<String>> = partitions
+                .iter()
+                .map(|p| {
+                    p.iter()
+                        .find(|(k, _)| k == key)
+                        .and_then(|(_, v)| v.clone())
+                })
+                .collect();
+            make_partition_series(key, &values)
+        })
+        .collect::<PolarsResult<_>>()?;
+
+    DataFrame::new(columns)
+}
+
+/// Returns the expressions adding the partition values of row `index` as constant columns.
+fn partition_exprs(partitions: &DataFrame, index: usize) -> PolarsResult<Vec<Expr>> {
+    partitions
+        .get_columns()
+        .iter()
+        .map(|s| {
+            // Literals of utf8 values cannot be cast, even to utf8, the temporal literals are
+            // created from chrono values as datetimes.
+            let value = match s.get(index)? {
+                AnyValue::Int64(v) => lit(v),
+                AnyValue::Float64(v) => lit(v),
+                AnyValue::Boolean(v) => lit(v),
+                AnyValue::Utf8(v) => lit(v),
+                AnyValue::Date(v) => lit(date32_to_date(v)).cast(DataType::Date),
+                AnyValue::Datetime(v, _, _) => {
+                    lit(timestamp_us_to_datetime(v)).cast(s.dtype().clone())
+                }
+                _ => lit(NULL).cast(s.dtype().clone()),
+            };
+            Ok(value.alias(s.name()))
+        })
+        .collect()
+}
+
+/// Returns the indices of the files whose partition values match `predicate`.
+fn filter_partitions(partitions: &DataFrame, predicate: Expr) -> PolarsResult<Vec<usize>> {
+    const INDEX: &str = "__polars_file_index";
+    let df = partitions
+        .clone()
+        .lazy()
+        .with_row_count(INDEX, None)
+        .filter(predicate)
+        .select([col(INDEX)])
+        .collect()?;
+    Ok(df
+        .column(INDEX)?
+        .idx()?
+        .into_no_null_iter()
+        .map(|i| i as usize)
+        .collect())
+}
+
+/// Scans a file as a LazyFrame.
+pub(crate) type ScanFile = dyn Fn(&Path) -> PolarsResult<LazyFrame> + Send + Sync;
+
+/// Returns the union of the files of `paths` at `indices`, the partition values of each file are
+/// added as constant columns.
+pub(crate) fn scan_union(
+    paths: &[PathBuf],
+    partitions: &DataFrame,
+    indices: &[usize],
+    rechunk: bool,
+    scan_file: &ScanFile,
+) -> PolarsResult<LazyFrame> {
+    let scan = |i: usize| {
+        let lf = scan_file(&paths[i])?;
+        if partitions.width() == 0 {
+            return Ok(lf);
+        }
+        Ok(lf.with_columns(partition_exprs(partitions, i)?))
+    };
+
+    if indices.is_empty() {
+        // All the files were pruned, scan the first one to get an empty frame with the right
+        // schema.
+        return Ok(scan(0)?.limit(0));
+    }
+
+    let frames = indices
+        .iter()
+        .map(|&i| scan(i))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    concat(
+        &frames,
+        UnionArgs {
+            rechunk,
+            ..Default::default()
+        },
+    )
+}
+
+/// Appends the expressions combined by `&` in `expr` to `out`.
+fn split_conjunctions<'a>(expr: &'a Expr, out: &mut Vec<&'a Expr>) {
+    match expr {
+        Expr::BinaryExpr {
+            left,
+            op: Operator::And,
+            right,
+        } => {
+            split_conjunctions(left, out);
+            split_conjunctions(right, out);
+        }
+        expr => out.push(expr),
+    }
+}
+
+/// A scan of the union of hive partitioned files, the predicates pushed down by polars are
+/// evaluated on the partition values first so that the files which cannot match are not read.
+struct HiveScan {
+    paths: Vec<PathBuf>,
+    partitions: DataFrame,
+    rechunk: bool,
+    scan_file: Box<ScanFile>,
+}
+
+impl HiveScan {
+    /// Returns whether `expr` only reads partition columns and evaluates row by row, so that
+    /// its value for a file is its value for the partition values of the file.
+    fn is_partition_predicate(&self, expr: &Expr) -> bool {
+        let names = self.partitions.get_column_names();
+        expr.into_iter().all(|e| match e {
+            Expr::Column(name) => names.contains(&name.as_ref()),
+            Expr::Literal(_)
+            | Expr::BinaryExpr { .. }
+            | Expr::Cast { .. }
+            | Expr::Ternary { .. }
+            | Expr::Alias(..) => true,
+            Expr::Function { options, .. } => {
+                !options.is_groups_sensitive() && !options.auto_explode
+            }
+            _ => false,
+        })
+    }
+
+    /// Returns the indices of the files which can match `predicate`.
+    fn matching_files(&self, predicate: &Expr) -> PolarsResult<Vec<usize>> {
+        let mut conjunctions = Vec::new();
+        split_conjunctions(predicate, &mut conjunctions);
+        let partition_predicate = conjunctions
+            .into_iter()
+            .filter(|e| self.is_partition_predicate(e))
+            .cloned()
+            .reduce(|a, b| a.and(b));
+
+        match partition_predicate {
+            Some(predicate) => filter_partitions(&self.partitions, predicate),
+            None => Ok((0..self.paths.len()).collect()),
+        }
+    }
+}
+
+impl AnonymousScan for HiveScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, options: AnonymousScanOptions) -> PolarsResult<DataFrame> {
+        let indices = match &options.predicate {
+            Some(predicate) => self.matching_files(predicate)?,
+            None => (0..self.paths.len()).collect(),
+        };
+
+        let lf = scan_union(
+            &self.paths,
+            &self.partitions,
+            &indices,
+            self.rechunk,
+            &*self.scan_file,
+        )?;
+        // The predicate is not applied by polars once pushed down.
+        match options.predicate {
+            Some(predicate) => lf.filter(predicate).collect(),
+            None => lf.collect(),
+        }
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+}
+
+/// Returns a LazyFrame scanning the union of the files of `paths` with their `partitions` as
+/// columns, the files whose partition values do not match the filters applied to it are skipped.
+pub(crate) fn scan_partitioned(
+    paths: Vec<PathBuf>,
+    partitions: DataFrame,
+    rechunk: bool,
+    scan_file: Box<ScanFile>,
+) -> PolarsResult<LazyFrame> {
+    let schema = scan_union(&paths, &partitions, &[0], false, &*scan_file)?.schema()?;
+    let scan = HiveScan {
+        paths,
+        partitions,
+        rechunk,
+        scan_file,
+    };
+    LazyFrame::anonymous_scan(
+        Arc::new(scan),
+        ScanArgsAnonymous {
+            schema: Some(schema.as_ref().clone()),
+            name: "HIVE SCAN",
+            ..Default::default()
+        },
+    )
+}
+
+/// Encodes the characters which cannot appear in a partition directory name as `%XX`.
+fn percent_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '/' | '\\' | '=' | '%' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '\n' | '\r' => {
+                out.push_str(&format!("%{:02X}", c as u32))
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Returns the `key=value` directory name of a partition value.
+fn partition_dir(name: &str, value: AnyValue) -> String {
+    let value = match value {
+        AnyValue::Null => HIVE_DEFAULT_PARTITION.to_owned(),
+        AnyValue::Utf8(v) => percent_encode(v),
+        v => percent_encode(&v.to_string()),
+    };
+    format!("{}={}", percent_encode(name), value)
+}
+
+/// Writes `df` as a tree of `key=value/` directories under `root`, one level per column of
+/// `partition_by`, with one parquet file per partition. The partition columns are not written
+/// to the files.
+pub(crate) fn write_partitioned(
+    df: &DataFrame,
+    root: &Path,
+    partition_by: &[String],
+    options: &ParquetWriteOptions,
+) -> PolarsResult<()> {
+    polars_ensure!(!partition_by.is_empty(), ComputeError: "no partition columns given");
+
+    for mut part in df.partition_by_stable(partition_by.to_vec(), true)? {
+        let mut dir = root.to_path_buf();
+        for name in partition_by {
+            dir.push(partition_dir(name, part.column(name)?.get(0)?));
+        }
+        std::fs::create_dir_all(&dir)?;
+
+        for name in partition_by {
+            let _ = part.drop_in_place(name)?;
+        }
+
+        let file = std::fs::File::create(dir.join("data.parquet"))?;
+        make_parquet_writer(file, options).finish(&mut part)?;
+    }
+
+    Ok(())
+}
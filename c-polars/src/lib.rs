@@ -4,38 +4,215 @@
 use std::ffi::c_void;
 use std::io::Write;
 
+use polars::io::mmap::MmapBytesReader;
 use polars::prelude::*;
 use polars_core::utils::arrow::{
     self,
     array::StructArray,
-    ffi::{self, ArrowArray, ArrowSchema},
+    ffi::{self, ArrowArray, ArrowArrayStream, ArrowSchema},
 };
 
+mod csv;
 mod expr;
+mod hive;
+mod parquet;
 mod series;
+mod validate;
 mod value;
 
+use validate::polars_carrow_validation_t;
+use value::polars_value_type_t;
+
 #[no_mangle]
 pub unsafe extern "C" fn polars_version(out: *mut *const u8) -> usize {
-    let v = polars::VERSION;
-    if !out.is_null() {
-        *out = v.as_ptr();
-    }
-    v.len()
+    catch_panic(|| {
+        let v = polars::VERSION;
+        if !out.is_null() {
+            *out = v.as_ptr();
+        }
+        v.len()
+    })
 }
 
 /// The callback provided for display functions, returns -1 on error.
 type IOCallback =
     unsafe extern "cdecl" fn(user: *const c_void, data: *const u8, len: usize) -> isize;
 
+/// The kind of a `polars_error_t`, it mirrors the variants of `PolarsError`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum polars_error_kind_t {
+    PolarsErrorKindColumnNotFound,
+    PolarsErrorKindSchemaMismatch,
+    PolarsErrorKindComputeError,
+    PolarsErrorKindInvalidOperation,
+    PolarsErrorKindIo,
+    PolarsErrorKindShapeMismatch,
+    PolarsErrorKindOutOfBounds,
+    PolarsErrorKindNoData,
+    PolarsErrorKindDuplicate,
+    PolarsErrorKindUtf8,
+    /// A panic was caught at the boundary of the C API.
+    PolarsErrorKindPanic,
+}
+
 pub struct polars_error_t {
+    kind: polars_error_kind_t,
     msg: String,
 }
 
-fn make_error<E: ToString>(err: E) -> *const polars_error_t {
-    Box::into_raw(Box::new(polars_error_t {
-        msg: err.to_string(),
-    }))
+/// Errors which can be returned as a `polars_error_t`.
+trait ErrorKind: ToString {
+    fn kind(&self) -> polars_error_kind_t {
+        polars_error_kind_t::PolarsErrorKindComputeError
+    }
+}
+
+impl ErrorKind for std::str::Utf8Error {
+    fn kind(&self) -> polars_error_kind_t {
+        polars_error_kind_t::PolarsErrorKindUtf8
+    }
+}
+
+impl ErrorKind for std::io::Error {
+    fn kind(&self) -> polars_error_kind_t {
+        polars_error_kind_t::PolarsErrorKindIo
+    }
+}
+
+impl ErrorKind for arrow::error::Error {
+    fn kind(&self) -> polars_error_kind_t {
+        use arrow::error::Error;
+        use polars_error_kind_t::*;
+        match self {
+            Error::Io(_) => PolarsErrorKindIo,
+            Error::InvalidArgumentError(_) => PolarsErrorKindInvalidOperation,
+            Error::NotYetImplemented(_) => PolarsErrorKindInvalidOperation,
+            _ => PolarsErrorKindComputeError,
+        }
+    }
+}
+
+impl ErrorKind for PolarsError {
+    fn kind(&self) -> polars_error_kind_t {
+        use polars_error_kind_t::*;
+        match self {
+            PolarsError::ArrowError(err) => err.kind(),
+            PolarsError::ColumnNotFound(_)
+            | PolarsError::SchemaFieldNotFound(_)
+            | PolarsError::StructFieldNotFound(_) => PolarsErrorKindColumnNotFound,
+            PolarsError::ComputeError(_) => PolarsErrorKindComputeError,
+            PolarsError::Duplicate(_) => PolarsErrorKindDuplicate,
+            PolarsError::InvalidOperation(_) => PolarsErrorKindInvalidOperation,
+            PolarsError::Io(_) => PolarsErrorKindIo,
+            PolarsError::NoData(_) => PolarsErrorKindNoData,
+            PolarsError::SchemaMismatch(_) | PolarsError::StringCacheMismatch(_) => {
+                PolarsErrorKindSchemaMismatch
+            }
+            PolarsError::ShapeMismatch(_) => PolarsErrorKindShapeMismatch,
+        }
+    }
+}
+
+fn make_error<E: ErrorKind>(err: E) -> *const polars_error_t {
+    make_error_with_kind(err.kind(), err.to_string())
+}
+
+fn make_error_with_kind(kind: polars_error_kind_t, msg: String) -> *const polars_error_t {
+    Box::into_raw(Box::new(polars_error_t { kind, msg }))
+}
+
+thread_local! {
+    /// The message and location of the last panic on this thread, set by the panic hook.
+    static LAST_PANIC: std::cell::RefCell<Option<String>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Values returned by the entry points of the C API when a panic is caught.
+trait PanicSentinel {
+    fn from_panic(msg: String) -> Self;
+}
+
+impl PanicSentinel for *const polars_error_t {
+    fn from_panic(msg: String) -> Self {
+        make_error_with_kind(polars_error_kind_t::PolarsErrorKindPanic, msg)
+    }
+}
+
+/// The functions which cannot return an error return their sentinel value, the panic message
+/// is only reported by the panic hook which was installed before the first call.
+macro_rules! impl_panic_sentinel {
+    ($t: ty, $v: expr) => {
+        impl PanicSentinel for $t {
+            fn from_panic(_msg: String) -> Self {
+                $v
+            }
+        }
+    };
+}
+
+impl_panic_sentinel!((), ());
+impl_panic_sentinel!(bool, false);
+impl_panic_sentinel!(usize, 0);
+impl_panic_sentinel!(*const polars_expr_t, std::ptr::null());
+impl_panic_sentinel!(*const polars_value_t, std::ptr::null());
+impl_panic_sentinel!(ArrowSchema, ArrowSchema::empty());
+impl_panic_sentinel!(
+    polars_value_type_t,
+    polars_value_type_t::PolarsValueTypeUnknown
+);
+impl_panic_sentinel!(
+    polars_error_kind_t,
+    polars_error_kind_t::PolarsErrorKindPanic
+);
+
+impl<T> PanicSentinel for *mut T {
+    fn from_panic(_msg: String) -> Self {
+        std::ptr::null_mut()
+    }
+}
+
+/// Runs `f` and catches its panics so that they don't unwind into the host. A caught panic is
+/// returned as an error of kind `PolarsErrorKindPanic` with the panic message and location, or
+/// as the sentinel value of functions which cannot return errors: null, 0, false, an unknown
+/// value type or a released ArrowSchema.
+fn catch_panic<T: PanicSentinel>(f: impl FnOnce() -> T) -> T {
+    static HOOK: std::sync::Once = std::sync::Once::new();
+    HOOK.call_once(|| {
+        // The hook records the panic for this thread and then runs the hook it replaces, which
+        // may belong to the host or to another library.
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let msg = panic_message(info.payload());
+            let msg = match info.location() {
+                Some(location) => format!("panic at {location}: {msg}"),
+                None => format!("panic: {msg}"),
+            };
+            LAST_PANIC.with(|last| *last.borrow_mut() = Some(msg));
+            previous(info);
+        }));
+    });
+
+    LAST_PANIC.with(|last| last.borrow_mut().take());
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
+        Ok(value) => value,
+        Err(payload) => {
+            // Panics resumed from other threads, e.g. in the thread pool, did not run the hook on
+            // this thread.
+            let msg = LAST_PANIC.with(|last| last.borrow_mut().take());
+            T::from_panic(msg.unwrap_or_else(|| format!("panic: {}", panic_message(&*payload))))
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg
+    } else {
+        "unknown panic"
+    }
 }
 
 #[no_mangle]
@@ -43,21 +220,35 @@ pub unsafe extern "C" fn polars_error_message(
     err: *const polars_error_t,
     data: *mut *const u8,
 ) -> usize {
-    assert!(!err.is_null());
-    assert!(!data.is_null());
-    *data = (*err).msg.as_ptr();
-    return (*err).msg.len();
+    catch_panic(|| {
+        assert!(!err.is_null());
+        assert!(!data.is_null());
+        *data = (*err).msg.as_ptr();
+        return (*err).msg.len();
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_error_kind(err: *const polars_error_t) -> polars_error_kind_t {
+    catch_panic(|| {
+        assert!(!err.is_null());
+        (*err).kind
+    })
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_error_destroy(err: *const polars_error_t) {
-    assert!(!err.is_null());
-    let _ = Box::from_raw(err.cast_mut());
+    catch_panic(|| {
+        assert!(!err.is_null());
+        let _ = Box::from_raw(err.cast_mut());
+    })
 }
 
-// TODO: investigate what the lifetime implies.
-pub struct polars_value_t<'a> {
-    inner: AnyValue<'a>,
+/// A value which owns its data, it stays valid after the series it was taken from is destroyed.
+pub struct polars_value_t {
+    inner: AnyValue<'static>,
+    /// The time zone of a datetime, which `AnyValue` only borrows from the type of its series.
+    time_zone: Option<TimeZone>,
 }
 
 pub struct polars_dataframe_t {
@@ -84,20 +275,38 @@ fn make_dataframe(df: DataFrame) -> *mut polars_dataframe_t {
     Box::into_raw(Box::new(polars_dataframe_t { inner: df }))
 }
 
+/// Creates an empty DataFrame, it must be destroyed with `polars_dataframe_destroy`.
 #[no_mangle]
-pub fn polars_dataframe_new() -> *mut polars_dataframe_t {
-    make_dataframe(DataFrame::empty())
+pub extern "C" fn polars_dataframe_new() -> *mut polars_dataframe_t {
+    catch_panic(|| make_dataframe(DataFrame::empty()))
 }
 
+/// Errors are ignored, prefer `polars_dataframe_size_checked` which reports them.
 #[no_mangle]
 pub unsafe extern "C" fn polars_dataframe_size(
     df: *mut polars_dataframe_t,
     rows: *mut usize,
     cols: *mut usize,
 ) {
-    let df = &(*df).inner;
-    *rows = df.height();
-    *cols = df.width();
+    let err = polars_dataframe_size_checked(df, rows, cols);
+    if !err.is_null() {
+        polars_error_destroy(err);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_size_checked(
+    df: *mut polars_dataframe_t,
+    rows: *mut usize,
+    cols: *mut usize,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!df.is_null());
+        let df = &(*df).inner;
+        *rows = df.height();
+        *cols = df.width();
+        std::ptr::null()
+    })
 }
 
 /// Creates a DataFrame from a series of ArrowArray and ArrowSchema compatible the arrow C-ABI.
@@ -115,42 +324,187 @@ pub extern "C" fn polars_dataframe_new_from_carrow(
     cfield: *const ArrowSchema,
     carray: ArrowArray,
 ) -> *mut polars_dataframe_t {
-    // Safety: the field ptr is expected to be a valid pointer to an ArrowSchema according to
-    // the C Data interface.
-    let Ok(field) = (unsafe { ffi::import_field_from_c(&*cfield) }) else {
-        return std::ptr::null_mut();
-    };
+    catch_panic(|| {
+        // Safety: the field ptr is expected to be a valid pointer to an ArrowSchema according to
+        // the C Data interface.
+        let Ok(field) = (unsafe { ffi::import_field_from_c(&*cfield) }) else {
+            return std::ptr::null_mut();
+        };
 
-    // Safety: carray will not be destroyed at the end of the function since import_array_from_c
-    // takes ownership of it. Therefore, it should be destroyed once the dataframe is destroyed
-    // using polars_dataframe_destroy.
-    let Ok(array) = (unsafe { ffi::import_array_from_c(carray, field.data_type.clone()) }) else {
-        return std::ptr::null_mut();
-    };
+        // Safety: carray will not be destroyed at the end of the function since import_array_from_c
+        // takes ownership of it. Therefore, it should be destroyed once the dataframe is destroyed
+        // using polars_dataframe_destroy.
+        let Ok(array) = (unsafe { ffi::import_array_from_c(carray, field.data_type.clone()) })
+        else {
+            return std::ptr::null_mut();
+        };
 
-    let Some(sarray) = array.as_any().downcast_ref::<StructArray>() else {
-        // caller is expected to provide a struct array (encoding +s) with field
-        // being the columns.
-        return std::ptr::null_mut();
-    };
+        let Some(sarray) = array.as_any().downcast_ref::<StructArray>() else {
+            // caller is expected to provide a struct array (encoding +s) with field
+            // being the columns.
+            return std::ptr::null_mut();
+        };
 
-    let Ok(df) = DataFrame::try_from(sarray.clone()) else {
-        return std::ptr::null_mut();
-    };
+        let Ok(df) = DataFrame::try_from(sarray.clone()) else {
+            return std::ptr::null_mut();
+        };
 
-    make_dataframe(df)
+        make_dataframe(df)
+    })
+}
+
+/// Creates a DataFrame as `polars_dataframe_new_from_carrow` does, but reports the reason of
+/// failures and runs the checks of `validation` on the array before accepting it. The checks are
+/// skipped when `validation` is null. The sizes of the buffers are not part of the Arrow C Data
+/// interface, so buffers shorter than their array are not detected.
+///
+/// # Safety
+/// The ownership of `carray` is transferred as in `polars_dataframe_new_from_carrow`, it is
+/// released when an error is returned.
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_new_from_carrow_validated(
+    cfield: *const ArrowSchema,
+    carray: ArrowArray,
+    validation: *const polars_carrow_validation_t,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!cfield.is_null());
+        let field = match ffi::import_field_from_c(&*cfield) {
+            Ok(field) => field,
+            Err(err) => return make_error(err),
+        };
+
+        if !validation.is_null() {
+            if let Err(err) = validate::validate_carray(&carray, &field.data_type, &*validation) {
+                return make_error(err);
+            }
+        }
+
+        let array = match ffi::import_array_from_c(carray, field.data_type.clone()) {
+            Ok(array) => array,
+            Err(err) => return make_error(err),
+        };
+
+        let Some(sarray) = array.as_any().downcast_ref::<StructArray>() else {
+            return make_error(polars_err!(
+                SchemaMismatch: "expected a struct array whose fields are the columns, got {:?}",
+                array.data_type()
+            ));
+        };
+
+        if !validation.is_null() {
+            if let Err(err) = validate::validate_array(sarray, &*validation) {
+                return make_error(err);
+            }
+        }
+
+        match DataFrame::try_from(sarray.clone()) {
+            Ok(df) => *out = make_dataframe(df),
+            Err(err) => return make_error(err),
+        }
+
+        std::ptr::null()
+    })
+}
+
+/// Creates a DataFrame from the record batches of an Arrow C stream, each batch must be a
+/// struct array whose fields are the columns and becomes a chunk of the DataFrame. The stream
+/// is consumed and released by this function.
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_new_from_carrow_stream(
+    stream: *mut ArrowArrayStream,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!stream.is_null());
+        // Safety: the stream is moved out and released when the reader is dropped, the caller's
+        // stream is left marked as released.
+        let stream = Box::new(std::ptr::replace(stream, ArrowArrayStream::empty()));
+        let mut reader = match ffi::ArrowArrayStreamReader::try_new(stream) {
+            Ok(reader) => reader,
+            Err(err) => return make_error(err),
+        };
+
+        let arrow::datatypes::DataType::Struct(fields) = reader.field().data_type() else {
+            return make_error(polars_err!(
+                SchemaMismatch: "expected a stream of struct arrays whose fields are the columns"
+            ));
+        };
+        let schema: Schema = fields.iter().collect();
+
+        let mut df: Option<DataFrame> = None;
+        while let Some(array) = reader.next() {
+            let array = match array {
+                Ok(array) => array,
+                Err(err) => return make_error(err),
+            };
+            let Some(sarray) = array.as_any().downcast_ref::<StructArray>() else {
+                return make_error(polars_err!(
+                    SchemaMismatch: "expected a stream of struct arrays whose fields are the columns"
+                ));
+            };
+            let chunk = match DataFrame::try_from(sarray.clone()) {
+                Ok(chunk) => chunk,
+                Err(err) => return make_error(err),
+            };
+            match df.as_mut() {
+                Some(df) => {
+                    if let Err(err) = df.vstack_mut(&chunk) {
+                        return make_error(err);
+                    }
+                }
+                None => df = Some(chunk),
+            }
+        }
+
+        *out = make_dataframe(df.unwrap_or_else(|| DataFrame::from(&schema)));
+        std::ptr::null()
+    })
 }
 
 /// Returns a ArrowSchema describing the dataframe's schema according to Arrow C Data interface.
 #[no_mangle]
 pub unsafe extern "C" fn polars_dataframe_schema(df: *mut polars_dataframe_t) -> ArrowSchema {
-    let schema = (*df).inner.schema().to_arrow();
-    let structfield = arrow::datatypes::Field::new(
-        "polars.dataframe",
-        arrow::datatypes::DataType::Struct(schema.fields),
-        false,
-    );
-    ffi::export_field_to_c(&structfield)
+    catch_panic(|| {
+        let schema = (*df).inner.schema().to_arrow();
+        let structfield = arrow::datatypes::Field::new(
+            "polars.dataframe",
+            arrow::datatypes::DataType::Struct(schema.fields),
+            false,
+        );
+        ffi::export_field_to_c(&structfield)
+    })
+}
+
+/// Exports the DataFrame to `out` according to the Arrow C Stream interface, each chunk of the
+/// dataframe is a struct array whose fields are the columns. The chunks are not copied.
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_export_carrow_stream(
+    df: *mut polars_dataframe_t,
+    out: *mut ArrowArrayStream,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!df.is_null());
+        assert!(!out.is_null());
+        *out = make_carrow_stream((*df).inner.clone());
+        std::ptr::null()
+    })
+}
+
+fn make_carrow_stream(mut df: DataFrame) -> ArrowArrayStream {
+    df.align_chunks();
+    let fields = df.schema().to_arrow().fields;
+    let dtype = arrow::datatypes::DataType::Struct(fields);
+    let chunks: Vec<_> = df
+        .iter_chunks()
+        .map(|chunk| {
+            let array = StructArray::new(dtype.clone(), chunk.into_arrays(), None);
+            Ok(Box::new(array) as Box<dyn arrow::array::Array>)
+        })
+        .collect();
+    let field = arrow::datatypes::Field::new("polars.dataframe", dtype, false);
+    ffi::export_iterator(Box::new(chunks.into_iter()), field)
 }
 
 #[no_mangle]
@@ -159,19 +513,34 @@ pub unsafe extern "C" fn polars_dataframe_new_from_series(
     nseries: usize,
     out: *mut *mut polars_dataframe_t,
 ) -> *const polars_error_t {
-    let slice: &[*mut polars_series_t] = std::slice::from_raw_parts(series, nseries);
-    let series: Vec<Series> = slice.iter().map(|s| (**s).inner.clone()).collect();
-    let df = match DataFrame::new(series) {
-        Ok(df) => df,
-        Err(err) => return make_error(err),
-    };
-    *out = make_dataframe(df);
-    std::ptr::null()
+    catch_panic(|| {
+        let slice: &[*mut polars_series_t] = std::slice::from_raw_parts(series, nseries);
+        let series: Vec<Series> = slice.iter().map(|s| (**s).inner.clone()).collect();
+        let df = match DataFrame::new(series) {
+            Ok(df) => df,
+            Err(err) => return make_error(err),
+        };
+        *out = make_dataframe(df);
+        std::ptr::null()
+    })
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_dataframe_destroy(df: *mut polars_dataframe_t) {
-    let _ = Box::from_raw(df);
+    catch_panic(|| {
+        let _ = Box::from_raw(df);
+    })
+}
+
+/// Returns a new handle sharing the columns of `df`, each handle must be destroyed.
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_clone(
+    df: *mut polars_dataframe_t,
+) -> *mut polars_dataframe_t {
+    catch_panic(|| {
+        assert!(!df.is_null());
+        make_dataframe((*df).inner.clone())
+    })
 }
 
 #[no_mangle]
@@ -180,236 +549,1955 @@ pub unsafe extern "C" fn polars_dataframe_write_parquet(
     user: *const c_void,
     callback: IOCallback,
 ) -> *const polars_error_t {
-    let df = &mut (*df).inner;
+    catch_panic(|| {
+        let df = &mut (*df).inner;
 
-    let w = UserIOCallback(callback, user);
-    if let Err(err) = ParquetWriter::new(w).finish(df) {
-        return make_error(err);
-    }
+        let w = UserIOCallback(callback, user);
+        if let Err(err) = ParquetWriter::new(w).finish(df) {
+            return make_error(err);
+        }
 
-    std::ptr::null()
+        std::ptr::null()
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn polars_dataframe_read_parquet(
-    path: *const u8,
-    pathlen: usize,
-    out: *mut *mut polars_dataframe_t,
-) -> *const polars_error_t {
-    let path = unsafe { std::slice::from_raw_parts(path, pathlen) };
-    let path = match std::str::from_utf8(path) {
-        Ok(path) => path,
-        Err(err) => return make_error(err),
-    };
+#[repr(C)]
+pub enum polars_parquet_compression_t {
+    PolarsParquetCompressionUncompressed,
+    PolarsParquetCompressionSnappy,
+    PolarsParquetCompressionGzip,
+    PolarsParquetCompressionLzo,
+    PolarsParquetCompressionBrotli,
+    PolarsParquetCompressionZstd,
+    PolarsParquetCompressionLz4Raw,
+}
 
-    let file = match std::fs::OpenOptions::new().read(true).open(path) {
-        Ok(file) => file,
-        Err(err) => return make_error(err),
-    };
+/// Options used by `polars_dataframe_write_parquet_with_options`, use
+/// `polars_parquet_write_options_default` to get the default options.
+#[repr(C)]
+pub struct polars_parquet_write_options_t {
+    compression: polars_parquet_compression_t,
+    /// Compression level used by gzip, brotli and zstd, a negative level uses the codec default.
+    compression_level: i32,
+    /// Whether to compute and write column statistics.
+    statistics: bool,
+    /// Number of rows per row group, `SIZE_MAX` uses the polars default.
+    row_group_size: usize,
+    /// Size limit of data pages in bytes, `SIZE_MAX` uses the polars default.
+    data_pagesize_limit: usize,
+}
 
-    match ParquetReader::new(file).finish() {
-        Ok(df) => unsafe {
-            *out = make_dataframe(df);
-        },
-        Err(err) => return make_error(err),
+impl polars_parquet_write_options_t {
+    fn to_compression(&self) -> PolarsResult<ParquetCompression> {
+        use polars_parquet_compression_t::*;
+        let level = (self.compression_level >= 0).then_some(self.compression_level);
+        Ok(match self.compression {
+            PolarsParquetCompressionUncompressed => ParquetCompression::Uncompressed,
+            PolarsParquetCompressionSnappy => ParquetCompression::Snappy,
+            PolarsParquetCompressionGzip => ParquetCompression::Gzip(
+                level
+                    .map(|level| GzipLevel::try_new(level.try_into().unwrap_or(u8::MAX)))
+                    .transpose()?,
+            ),
+            PolarsParquetCompressionLzo => ParquetCompression::Lzo,
+            PolarsParquetCompressionBrotli => ParquetCompression::Brotli(
+                level
+                    .map(|level| BrotliLevel::try_new(level as u32))
+                    .transpose()?,
+            ),
+            PolarsParquetCompressionZstd => {
+                ParquetCompression::Zstd(level.map(ZstdLevel::try_new).transpose()?)
+            }
+            PolarsParquetCompressionLz4Raw => ParquetCompression::Lz4Raw,
+        })
     }
 
-    std::ptr::null()
+    pub(crate) fn to_write_options(&self) -> PolarsResult<ParquetWriteOptions> {
+        Ok(ParquetWriteOptions {
+            compression: self.to_compression()?,
+            statistics: self.statistics,
+            row_group_size: make_optional_size(self.row_group_size),
+            data_pagesize_limit: make_optional_size(self.data_pagesize_limit),
+            maintain_order: true,
+        })
+    }
 }
 
-pub(crate) struct UserIOCallback(IOCallback, *const c_void);
-
-impl std::io::Write for UserIOCallback {
-    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        let n = unsafe { self.0(self.1, buf.as_ptr(), buf.len()) };
-        if n < 0 {
-            Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "user callback error",
-            ))
-        } else {
-            Ok(n as usize)
-        }
+#[no_mangle]
+pub extern "C" fn polars_parquet_write_options_default() -> polars_parquet_write_options_t {
+    polars_parquet_write_options_t {
+        compression: polars_parquet_compression_t::PolarsParquetCompressionZstd,
+        compression_level: -1,
+        statistics: false,
+        row_group_size: usize::MAX,
+        data_pagesize_limit: usize::MAX,
     }
+}
 
-    fn flush(&mut self) -> std::io::Result<()> {
-        Ok(())
-    }
+/// Creates a parquet writer configured with `options`.
+pub(crate) fn make_parquet_writer<W: std::io::Write>(
+    w: W,
+    options: &ParquetWriteOptions,
+) -> ParquetWriter<W> {
+    ParquetWriter::new(w)
+        .with_compression(options.compression)
+        .with_statistics(options.statistics)
+        .with_row_group_size(options.row_group_size)
+        .with_data_pagesize_limit(options.data_pagesize_limit)
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn polars_dataframe_show(
+pub unsafe extern "C" fn polars_dataframe_write_parquet_with_options(
     df: *mut polars_dataframe_t,
+    options: *const polars_parquet_write_options_t,
     user: *const c_void,
     callback: IOCallback,
-) {
-    let df = &(*df).inner;
-    let mut w = UserIOCallback(callback, user);
-    write!(w, "{df}").expect("failed to show dataframe");
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!options.is_null());
+        let df = &mut (*df).inner;
+
+        let options = match (*options).to_write_options() {
+            Ok(options) => options,
+            Err(err) => return make_error(err),
+        };
+
+        let w = UserIOCallback(callback, user);
+        if let Err(err) = make_parquet_writer(w, &options).finish(df) {
+            return make_error(err);
+        }
+
+        std::ptr::null()
+    })
 }
 
+/// Writes the DataFrame as a hive-partitioned dataset under the directory `root`, with one
+/// `key=value/` directory level per column in `partition_by` and one parquet file per partition.
+/// Null partition values are written as `__HIVE_DEFAULT_PARTITION__`.
 #[no_mangle]
-pub unsafe extern "C" fn polars_dataframe_get(
+pub unsafe extern "C" fn polars_dataframe_write_parquet_partitioned(
     df: *mut polars_dataframe_t,
-    name: *const u8,
-    len: usize,
-    out: *mut *mut polars_series_t,
+    root: *const u8,
+    rootlen: usize,
+    partition_by: *const *const u8,
+    partition_by_lens: *const usize,
+    n_partition_by: usize,
+    options: *const polars_parquet_write_options_t,
 ) -> *const polars_error_t {
-    let name = unsafe { std::slice::from_raw_parts(name, len) };
-    let name = match std::str::from_utf8(name) {
-        Ok(path) => path,
-        Err(err) => return make_error(err),
-    };
+    catch_panic(|| {
+        assert!(!df.is_null());
+        assert!(!options.is_null());
+        let df = &(*df).inner;
 
-    let df = &(*df).inner;
-    let mut series = match df.select_series(&[name]) {
-        Ok(series) => series,
-        Err(err) => return make_error(err),
-    };
+        let root = std::slice::from_raw_parts(root, rootlen);
+        let root = match std::str::from_utf8(root) {
+            Ok(root) => root,
+            Err(err) => return make_error(err),
+        };
 
-    let Some(series) = series.pop() else {
-        return make_error(format!("dataframe has not column {name}"));
-    };
+        let partition_by = match make_strings(partition_by, partition_by_lens, n_partition_by) {
+            Ok(partition_by) => partition_by,
+            Err(err) => return make_error(err),
+        };
 
-    *out = series::make_series(series);
+        let options = match (*options).to_write_options() {
+            Ok(options) => options,
+            Err(err) => return make_error(err),
+        };
 
-    std::ptr::null()
+        if let Err(err) =
+            hive::write_partitioned(df, std::path::Path::new(root), &partition_by, &options)
+        {
+            return make_error(err);
+        }
+
+        std::ptr::null()
+    })
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn polars_dataframe_lazy(
-    df: *mut polars_dataframe_t,
-) -> *mut polars_lazy_frame_t {
-    let df = &(*df).inner;
-    Box::into_raw(Box::new(polars_lazy_frame_t {
-        inner: df.clone().lazy(),
-    }))
+/// Options used by the parquet readers, use `polars_parquet_read_options_default` to get the
+/// default options.
+#[repr(C)]
+pub struct polars_parquet_read_options_t {
+    /// Names of the columns to read, all columns are read when both `n_columns` and
+    /// `n_projection` are 0.
+    columns: *const *const u8,
+    columns_lens: *const usize,
+    n_columns: usize,
+    /// Indices of the columns to read, ignored when columns are selected by name.
+    projection: *const usize,
+    n_projection: usize,
+    /// Stop reading after this number of rows, `SIZE_MAX` reads all the rows.
+    n_rows: usize,
+    /// Name of the row count column, no row count column is added when empty.
+    row_count_name: *const u8,
+    row_count_name_len: usize,
+    row_count_offset: u32,
+    parallel: polars_parallel_strategy_t,
+    /// Whether to use the statistics to skip pages which cannot match.
+    use_statistics: bool,
+    /// Whether to aggregate the chunks into a single chunk once read.
+    rechunk: bool,
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn polars_lazy_frame_destroy(df: *mut polars_lazy_frame_t) {
-    assert!(!df.is_null());
-    let _ = Box::from_raw(df);
+pub extern "C" fn polars_parquet_read_options_default() -> polars_parquet_read_options_t {
+    polars_parquet_read_options_t {
+        columns: std::ptr::null(),
+        columns_lens: std::ptr::null(),
+        n_columns: 0,
+        projection: std::ptr::null(),
+        n_projection: 0,
+        n_rows: usize::MAX,
+        row_count_name: std::ptr::null(),
+        row_count_name_len: 0,
+        row_count_offset: 0,
+        parallel: polars_parallel_strategy_t::PolarsParallelStrategyAuto,
+        use_statistics: true,
+        rechunk: false,
+    }
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn polars_lazy_frame_clone(
-    df: *mut polars_lazy_frame_t,
-) -> *mut polars_lazy_frame_t {
-    assert!(!df.is_null());
-    Box::into_raw(Box::new(polars_lazy_frame_t {
-        inner: (*df).inner.clone(),
-    }))
+pub unsafe extern "C" fn polars_dataframe_read_parquet(
+    path: *const u8,
+    pathlen: usize,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        let options = polars_parquet_read_options_default();
+        polars_dataframe_read_parquet_with_options(path, pathlen, &options, out)
+    })
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn polars_lazy_frame_sort(
-    df: *mut polars_lazy_frame_t,
-    exprs: *const *const polars_expr_t,
-    nexprs: usize,
-    descending: *const bool,
-    nulls_last: bool,
-    maintain_order: bool,
-) {
-    let exprs: Vec<Expr> = std::slice::from_raw_parts(exprs, nexprs)
-        .iter()
-        .map(|expr| (**expr).inner.clone())
-        .collect();
-    let descending = std::slice::from_raw_parts(descending, nexprs);
-    let mut df = Box::from_raw(df);
-    df.inner = df
-        .inner
-        .sort_by_exprs(&exprs, descending, nulls_last, maintain_order);
-    std::mem::forget(df);
+pub unsafe extern "C" fn polars_dataframe_read_parquet_with_options(
+    path: *const u8,
+    pathlen: usize,
+    options: *const polars_parquet_read_options_t,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        let path = std::slice::from_raw_parts(path, pathlen);
+        let path = match std::str::from_utf8(path) {
+            Ok(path) => path,
+            Err(err) => return make_error(err),
+        };
+
+        let file = match std::fs::OpenOptions::new().read(true).open(path) {
+            Ok(file) => file,
+            Err(err) => return make_error(err),
+        };
+
+        read_parquet(file, options, out)
+    })
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn polars_lazy_frame_concat(
-    lfs: *const *mut polars_lazy_frame_t,
-    n: usize,
-    out: *mut *mut polars_lazy_frame_t,
+pub unsafe extern "C" fn polars_dataframe_read_parquet_from_callbacks(
+    callbacks: polars_reader_callbacks_t,
+    options: *const polars_parquet_read_options_t,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    catch_panic(|| read_parquet(callbacks, options, out))
+}
+
+unsafe fn read_parquet<R: MmapBytesReader>(
+    reader: R,
+    options: *const polars_parquet_read_options_t,
+    out: *mut *mut polars_dataframe_t,
 ) -> *const polars_error_t {
-    let frames: Vec<LazyFrame> = (1..n).map(|i| (**lfs.add(i)).inner.clone()).collect();
+    assert!(!options.is_null());
+    let options = &*options;
+
+    let columns = match make_strings(options.columns, options.columns_lens, options.n_columns) {
+        Ok(columns) if !columns.is_empty() => Some(columns),
+        Ok(_) => None,
+        Err(err) => return make_error(err),
+    };
 
-    let df = match concat(&frames, UnionArgs::default()) {
-        Ok(df) => df,
+    let projection = (options.n_projection > 0)
+        .then(|| std::slice::from_raw_parts(options.projection, options.n_projection).to_vec());
+
+    let row_count = match make_row_count(
+        options.row_count_name,
+        options.row_count_name_len,
+        options.row_count_offset,
+    ) {
+        Ok(row_count) => row_count,
         Err(err) => return make_error(err),
     };
-    *out = Box::into_raw(Box::new(polars_lazy_frame_t { inner: df }));
+
+    let reader = ParquetReader::new(reader)
+        .with_columns(columns)
+        .with_projection(projection)
+        .with_n_rows(make_optional_size(options.n_rows))
+        .with_row_count(row_count)
+        .read_parallel(options.parallel.to_parallel_strategy())
+        .use_statistics(options.use_statistics)
+        .set_rechunk(options.rechunk);
+
+    match reader.finish() {
+        Ok(df) => *out = make_dataframe(df),
+        Err(err) => return make_error(err),
+    }
 
     std::ptr::null()
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn polars_lazy_frame_with_columns(
-    df: *mut polars_lazy_frame_t,
-    exprs: *const *const polars_expr_t,
-    nexprs: usize,
-) {
-    let exprs: Vec<Expr> = std::slice::from_raw_parts(exprs, nexprs)
-        .iter()
-        .map(|expr| (**expr).inner.clone())
-        .collect();
-    let mut df = Box::from_raw(df);
-    df.inner = df.inner.with_columns(&exprs);
-    std::mem::forget(df);
+#[repr(C)]
+pub enum polars_ipc_compression_t {
+    PolarsIpcCompressionUncompressed,
+    PolarsIpcCompressionLZ4,
+    PolarsIpcCompressionZSTD,
+}
+
+impl polars_ipc_compression_t {
+    fn to_ipc_compression(&self) -> Option<IpcCompression> {
+        use polars_ipc_compression_t::*;
+        match self {
+            PolarsIpcCompressionUncompressed => None,
+            PolarsIpcCompressionLZ4 => Some(IpcCompression::LZ4),
+            PolarsIpcCompressionZSTD => Some(IpcCompression::ZSTD),
+        }
+    }
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn polars_lazy_frame_select(
-    df: *mut polars_lazy_frame_t,
-    exprs: *const *const polars_expr_t,
-    nexprs: usize,
-) {
-    let exprs: Vec<Expr> = std::slice::from_raw_parts(exprs, nexprs)
-        .iter()
-        .map(|expr| (**expr).inner.clone())
-        .collect();
-    let mut df = Box::from_raw(df);
-    df.inner = df.inner.select(&exprs);
-    std::mem::forget(df);
+pub unsafe extern "C" fn polars_dataframe_write_ipc(
+    df: *mut polars_dataframe_t,
+    compression: polars_ipc_compression_t,
+    user: *const c_void,
+    callback: IOCallback,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        let df = &mut (*df).inner;
+
+        let w = UserIOCallback(callback, user);
+        if let Err(err) = IpcWriter::new(w)
+            .with_compression(compression.to_ipc_compression())
+            .finish(df)
+        {
+            return make_error(err);
+        }
+
+        std::ptr::null()
+    })
+}
+
+/// Options used by `polars_dataframe_read_ipc`, use `polars_ipc_read_options_default` to get the
+/// default options.
+#[repr(C)]
+pub struct polars_ipc_read_options_t {
+    /// Names of the columns to read, all columns are read when `n_columns` is 0.
+    columns: *const *const u8,
+    columns_lens: *const usize,
+    n_columns: usize,
+    /// Whether to memory-map the file instead of reading it into memory.
+    memory_map: bool,
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn polars_lazy_frame_filter(
-    df: *mut polars_lazy_frame_t,
-    expr: *const polars_expr_t,
-) {
-    assert!(!df.is_null());
-    assert!(!expr.is_null());
-    let mut df = Box::from_raw(df);
-    df.inner = df.inner.filter((*expr).inner.clone()); // NOTE: we clone the expr here, can we assume
-                                                       // that the function takes ownership of it?
-    std::mem::forget(df);
+pub extern "C" fn polars_ipc_read_options_default() -> polars_ipc_read_options_t {
+    polars_ipc_read_options_t {
+        columns: std::ptr::null(),
+        columns_lens: std::ptr::null(),
+        n_columns: 0,
+        memory_map: true,
+    }
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn polars_lazy_frame_collect(
-    df: *mut polars_lazy_frame_t,
+pub unsafe extern "C" fn polars_dataframe_read_ipc(
+    path: *const u8,
+    pathlen: usize,
+    options: *const polars_ipc_read_options_t,
     out: *mut *mut polars_dataframe_t,
 ) -> *const polars_error_t {
-    let df = (*df).inner.clone();
-    *out = make_dataframe(match df.collect() {
-        Ok(value) => value,
-        Err(err) => return make_error(err),
-    });
-    std::ptr::null()
+    catch_panic(|| {
+        let path = std::slice::from_raw_parts(path, pathlen);
+        let path = match std::str::from_utf8(path) {
+            Ok(path) => path,
+            Err(err) => return make_error(err),
+        };
+
+        let file = match std::fs::OpenOptions::new().read(true).open(path) {
+            Ok(file) => file,
+            Err(err) => return make_error(err),
+        };
+
+        read_ipc(file, options, out)
+    })
 }
 
+/// Reads an IPC file through the provided callbacks, memory-mapping is not available in this
+/// case and the `memory_map` option is ignored.
 #[no_mangle]
-pub unsafe extern "C" fn polars_lazy_frame_group_by(
-    df: *mut polars_lazy_frame_t,
-    exprs: *const *const polars_expr_t,
-    nexprs: usize,
+pub unsafe extern "C" fn polars_dataframe_read_ipc_from_callbacks(
+    callbacks: polars_reader_callbacks_t,
+    options: *const polars_ipc_read_options_t,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    catch_panic(|| read_ipc(callbacks, options, out))
+}
+
+unsafe fn read_ipc<R: MmapBytesReader>(
+    reader: R,
+    options: *const polars_ipc_read_options_t,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    assert!(!options.is_null());
+    let options = &*options;
+
+    let columns = match make_strings(options.columns, options.columns_lens, options.n_columns) {
+        Ok(columns) if !columns.is_empty() => Some(columns),
+        Ok(_) => None,
+        Err(err) => return make_error(err),
+    };
+
+    let reader = IpcReader::new(reader)
+        .with_columns(columns)
+        .memory_mapped(options.memory_map);
+
+    match reader.finish() {
+        Ok(df) => *out = make_dataframe(df),
+        Err(err) => return make_error(err),
+    }
+
+    std::ptr::null()
+}
+
+/// Reads a JSON file containing a single array of objects, one per row.
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_read_json(
+    path: *const u8,
+    pathlen: usize,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        let path = std::slice::from_raw_parts(path, pathlen);
+        let path = match std::str::from_utf8(path) {
+            Ok(path) => path,
+            Err(err) => return make_error(err),
+        };
+
+        let file = match std::fs::OpenOptions::new().read(true).open(path) {
+            Ok(file) => file,
+            Err(err) => return make_error(err),
+        };
+
+        read_json(file, out)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_read_json_from_callbacks(
+    callbacks: polars_reader_callbacks_t,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    catch_panic(|| read_json(callbacks, out))
+}
+
+unsafe fn read_json<R: MmapBytesReader>(
+    reader: R,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    match JsonReader::new(reader)
+        .with_json_format(JsonFormat::Json)
+        .finish()
+    {
+        Ok(df) => *out = make_dataframe(df),
+        Err(err) => return make_error(err),
+    }
+
+    std::ptr::null()
+}
+
+/// Reads a newline-delimited JSON file containing one object per line.
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_read_ndjson(
+    path: *const u8,
+    pathlen: usize,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        let path = std::slice::from_raw_parts(path, pathlen);
+        let path = match std::str::from_utf8(path) {
+            Ok(path) => path,
+            Err(err) => return make_error(err),
+        };
+
+        let file = match std::fs::OpenOptions::new().read(true).open(path) {
+            Ok(file) => file,
+            Err(err) => return make_error(err),
+        };
+
+        read_ndjson(file, out)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_read_ndjson_from_callbacks(
+    callbacks: polars_reader_callbacks_t,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    catch_panic(|| read_ndjson(callbacks, out))
+}
+
+unsafe fn read_ndjson<R: MmapBytesReader>(
+    reader: R,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    match JsonLineReader::new(reader).finish() {
+        Ok(df) => *out = make_dataframe(df),
+        Err(err) => return make_error(err),
+    }
+
+    std::ptr::null()
+}
+
+/// Writes the dataframe as a single JSON array containing one object per row.
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_write_json(
+    df: *mut polars_dataframe_t,
+    user: *const c_void,
+    callback: IOCallback,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        let df = &mut (*df).inner;
+
+        let w = UserIOCallback(callback, user);
+        if let Err(err) = JsonWriter::new(w)
+            .with_json_format(JsonFormat::Json)
+            .finish(df)
+        {
+            return make_error(err);
+        }
+
+        std::ptr::null()
+    })
+}
+
+/// Writes the dataframe as newline-delimited JSON with one object per line.
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_write_ndjson(
+    df: *mut polars_dataframe_t,
+    user: *const c_void,
+    callback: IOCallback,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        let df = &mut (*df).inner;
+
+        let w = UserIOCallback(callback, user);
+        if let Err(err) = JsonWriter::new(w)
+            .with_json_format(JsonFormat::JsonLines)
+            .finish(df)
+        {
+            return make_error(err);
+        }
+
+        std::ptr::null()
+    })
+}
+
+/// Options used by `polars_dataframe_read_csv`, string arrays are given as an array of pointers
+/// along with an array of lengths. Use `polars_csv_read_options_default` to get the default
+/// options.
+#[repr(C)]
+pub struct polars_csv_read_options_t {
+    separator: u8,
+    has_header: bool,
+    /// The quote character, 0 disables quoting.
+    quote_char: u8,
+    /// Lines starting with this character are skipped, 0 disables comments.
+    comment_char: u8,
+    skip_rows: usize,
+    /// Values which are interpreted as null in all columns.
+    null_values: *const *const u8,
+    null_values_lens: *const usize,
+    n_null_values: usize,
+    /// Stop reading after this number of rows, `SIZE_MAX` reads all the rows.
+    n_rows: usize,
+    /// Names of the columns to read, all columns are read when `n_columns` is 0.
+    columns: *const *const u8,
+    columns_lens: *const usize,
+    n_columns: usize,
+    /// Overrides the inferred dtypes of the named columns.
+    dtypes_names: *const *const u8,
+    dtypes_names_lens: *const usize,
+    dtypes: *const polars_value_type_t,
+    n_dtypes: usize,
+    try_parse_dates: bool,
+}
+
+#[no_mangle]
+pub extern "C" fn polars_csv_read_options_default() -> polars_csv_read_options_t {
+    polars_csv_read_options_t {
+        separator: b',',
+        has_header: true,
+        quote_char: b'"',
+        comment_char: 0,
+        skip_rows: 0,
+        null_values: std::ptr::null(),
+        null_values_lens: std::ptr::null(),
+        n_null_values: 0,
+        n_rows: usize::MAX,
+        columns: std::ptr::null(),
+        columns_lens: std::ptr::null(),
+        n_columns: 0,
+        dtypes_names: std::ptr::null(),
+        dtypes_names_lens: std::ptr::null(),
+        dtypes: std::ptr::null(),
+        n_dtypes: 0,
+        try_parse_dates: false,
+    }
+}
+
+/// Returns the expressions of `exprs`, which can be null when `n` is 0.
+unsafe fn make_exprs(exprs: *const *const polars_expr_t, n: usize) -> PolarsResult<Vec<Expr>> {
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+    polars_ensure!(!exprs.is_null(), InvalidOperation: "null array of {} expressions", n);
+    std::slice::from_raw_parts(exprs, n)
+        .iter()
+        .map(|expr| {
+            polars_ensure!(!expr.is_null(), InvalidOperation: "null expression");
+            Ok((**expr).inner.clone())
+        })
+        .collect()
+}
+
+/// Collects `n` strings given by their pointers and lengths.
+unsafe fn make_strings(
+    ptrs: *const *const u8,
+    lens: *const usize,
+    n: usize,
+) -> Result<Vec<String>, std::str::Utf8Error> {
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+    let ptrs = std::slice::from_raw_parts(ptrs, n);
+    let lens = std::slice::from_raw_parts(lens, n);
+    ptrs.iter()
+        .zip(lens)
+        .map(|(ptr, len)| {
+            std::str::from_utf8(std::slice::from_raw_parts(*ptr, *len)).map(str::to_owned)
+        })
+        .collect()
+}
+
+/// Builds a schema from `n` column names and dtypes, returns `None` when `n` is 0.
+unsafe fn make_schema(
+    names: *const *const u8,
+    lens: *const usize,
+    dtypes: *const polars_value_type_t,
+    n: usize,
+) -> Result<Option<Schema>, std::str::Utf8Error> {
+    if n == 0 {
+        return Ok(None);
+    }
+    let names = make_strings(names, lens, n)?;
+    let dtypes = std::slice::from_raw_parts(dtypes, n);
+    Ok(Some(
+        names
+            .iter()
+            .zip(dtypes)
+            .map(|(name, dtype)| Field::new(name, dtype.to_dtype()))
+            .collect(),
+    ))
+}
+
+/// Returns `None` for the `SIZE_MAX` sentinel used by options structs.
+fn make_optional_size(n: usize) -> Option<usize> {
+    (n != usize::MAX).then_some(n)
+}
+
+/// Returns `None` for the 0 sentinel used by options structs for characters.
+fn make_optional_char(c: u8) -> Option<u8> {
+    (c != 0).then_some(c)
+}
+
+/// Returns the row count column options, no row count column is added when the name is empty.
+unsafe fn make_row_count(
+    name: *const u8,
+    len: usize,
+    offset: u32,
+) -> Result<Option<polars::io::RowCount>, std::str::Utf8Error> {
+    Ok(make_optional_string(name, len)?.map(|name| polars::io::RowCount { name, offset }))
+}
+
+/// Returns `None` for empty strings which are used by options structs for unset values.
+unsafe fn make_optional_string(
+    ptr: *const u8,
+    len: usize,
+) -> Result<Option<String>, std::str::Utf8Error> {
+    if len == 0 {
+        return Ok(None);
+    }
+    std::str::from_utf8(std::slice::from_raw_parts(ptr, len)).map(|s| Some(s.to_owned()))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_read_csv(
+    path: *const u8,
+    pathlen: usize,
+    options: *const polars_csv_read_options_t,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        let path = std::slice::from_raw_parts(path, pathlen);
+        let path = match std::str::from_utf8(path) {
+            Ok(path) => path,
+            Err(err) => return make_error(err),
+        };
+
+        let file = match std::fs::OpenOptions::new().read(true).open(path) {
+            Ok(file) => file,
+            Err(err) => return make_error(err),
+        };
+
+        read_csv(file, options, out)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_read_csv_from_callbacks(
+    callbacks: polars_reader_callbacks_t,
+    options: *const polars_csv_read_options_t,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    catch_panic(|| read_csv(callbacks, options, out))
+}
+
+unsafe fn read_csv<R: MmapBytesReader>(
+    reader: R,
+    options: *const polars_csv_read_options_t,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    let reader = match make_csv_reader(reader, options) {
+        Ok(reader) => reader,
+        Err(err) => return make_error(err),
+    };
+
+    match reader.finish() {
+        Ok(df) => *out = make_dataframe(df),
+        Err(err) => return make_error(err),
+    }
+
+    std::ptr::null()
+}
+
+/// Builds a CsvReader configured from the read options.
+pub(crate) unsafe fn make_csv_reader<'a, R: MmapBytesReader + 'a>(
+    reader: R,
+    options: *const polars_csv_read_options_t,
+) -> Result<CsvReader<'a, R>, std::str::Utf8Error> {
+    assert!(!options.is_null());
+    let options = &*options;
+
+    let null_values = match make_strings(
+        options.null_values,
+        options.null_values_lens,
+        options.n_null_values,
+    )? {
+        mut values if values.len() == 1 => {
+            Some(NullValues::AllColumnsSingle(values.pop().unwrap()))
+        }
+        values if !values.is_empty() => Some(NullValues::AllColumns(values)),
+        _ => None,
+    };
+
+    let columns = make_strings(options.columns, options.columns_lens, options.n_columns)?;
+    let columns = (!columns.is_empty()).then_some(columns);
+
+    let dtypes = make_schema(
+        options.dtypes_names,
+        options.dtypes_names_lens,
+        options.dtypes,
+        options.n_dtypes,
+    )?
+    .map(Arc::new);
+
+    Ok(CsvReader::new(reader)
+        .with_delimiter(options.separator)
+        .has_header(options.has_header)
+        .with_quote_char(make_optional_char(options.quote_char))
+        .with_comment_char(make_optional_char(options.comment_char))
+        .with_skip_rows(options.skip_rows)
+        .with_null_values(null_values)
+        .with_n_rows(make_optional_size(options.n_rows))
+        .with_columns(columns)
+        .with_dtypes(dtypes)
+        .with_try_parse_dates(options.try_parse_dates))
+}
+
+#[repr(C)]
+pub enum polars_quote_style_t {
+    /// Quotes fields only when they contain a quote, the separator or a line terminator.
+    PolarsQuoteStyleNecessary,
+    PolarsQuoteStyleAlways,
+    PolarsQuoteStyleNonNumeric,
+}
+
+impl polars_quote_style_t {
+    fn to_quote_style(&self) -> QuoteStyle {
+        use polars_quote_style_t::*;
+        match self {
+            PolarsQuoteStyleNecessary => QuoteStyle::Necessary,
+            PolarsQuoteStyleAlways => QuoteStyle::Always,
+            PolarsQuoteStyleNonNumeric => QuoteStyle::NonNumeric,
+        }
+    }
+}
+
+/// Options used by `polars_dataframe_write_csv`, empty format strings use the polars defaults.
+/// Use `polars_csv_write_options_default` to get the default options.
+#[repr(C)]
+pub struct polars_csv_write_options_t {
+    separator: u8,
+    has_header: bool,
+    quote_char: u8,
+    quote_style: polars_quote_style_t,
+    date_format: *const u8,
+    date_format_len: usize,
+    datetime_format: *const u8,
+    datetime_format_len: usize,
+    time_format: *const u8,
+    time_format_len: usize,
+    /// Number of decimals written for floats, `SIZE_MAX` writes all of them.
+    float_precision: usize,
+    /// Representation of null values, empty by default.
+    null_value: *const u8,
+    null_value_len: usize,
+}
+
+#[no_mangle]
+pub extern "C" fn polars_csv_write_options_default() -> polars_csv_write_options_t {
+    polars_csv_write_options_t {
+        separator: b',',
+        has_header: true,
+        quote_char: b'"',
+        quote_style: polars_quote_style_t::PolarsQuoteStyleNecessary,
+        date_format: std::ptr::null(),
+        date_format_len: 0,
+        datetime_format: std::ptr::null(),
+        datetime_format_len: 0,
+        time_format: std::ptr::null(),
+        time_format_len: 0,
+        float_precision: usize::MAX,
+        null_value: std::ptr::null(),
+        null_value_len: 0,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_write_csv(
+    df: *mut polars_dataframe_t,
+    options: *const polars_csv_write_options_t,
+    user: *const c_void,
+    callback: IOCallback,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!options.is_null());
+        let options = &*options;
+        let df = &mut (*df).inner;
+
+        let date_format = match make_optional_string(options.date_format, options.date_format_len) {
+            Ok(format) => format,
+            Err(err) => return make_error(err),
+        };
+        let datetime_format =
+            match make_optional_string(options.datetime_format, options.datetime_format_len) {
+                Ok(format) => format,
+                Err(err) => return make_error(err),
+            };
+        let time_format = match make_optional_string(options.time_format, options.time_format_len) {
+            Ok(format) => format,
+            Err(err) => return make_error(err),
+        };
+        let null_value = match make_optional_string(options.null_value, options.null_value_len) {
+            Ok(value) => value.unwrap_or_default(),
+            Err(err) => return make_error(err),
+        };
+
+        let w = UserIOCallback(callback, user);
+        let mut writer = CsvWriter::new(w)
+            .with_delimiter(options.separator)
+            .has_header(options.has_header)
+            .with_quoting_char(options.quote_char)
+            .with_quote_style(options.quote_style.to_quote_style())
+            .with_date_format(date_format)
+            .with_datetime_format(datetime_format)
+            .with_time_format(time_format)
+            .with_float_precision(make_optional_size(options.float_precision))
+            .with_null_value(null_value);
+
+        if let Err(err) = writer.finish(df) {
+            return make_error(err);
+        }
+
+        std::ptr::null()
+    })
+}
+
+pub(crate) struct UserIOCallback(IOCallback, *const c_void);
+
+impl std::io::Write for UserIOCallback {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = unsafe { self.0(self.1, buf.as_ptr(), buf.len()) };
+        if n < 0 {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "user callback error",
+            ))
+        } else {
+            Ok(n as usize)
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The callback used to read at most `len` bytes into `data`, returns the number of bytes read,
+/// 0 at the end of the stream and -1 on error.
+type ReadCallback =
+    unsafe extern "cdecl" fn(user: *const c_void, data: *mut u8, len: usize) -> isize;
+
+/// The callback used to move the stream position, `whence` is 0 for an offset relative to the
+/// start of the stream, 1 for the current position and 2 for the end of the stream (as in
+/// `lseek`). Returns the new position from the start of the stream or -1 on error.
+type SeekCallback = unsafe extern "cdecl" fn(user: *const c_void, offset: i64, whence: i32) -> i64;
+
+/// The callback returning the current position from the start of the stream or -1 on error.
+type TellCallback = unsafe extern "cdecl" fn(user: *const c_void) -> i64;
+
+/// A host provided stream which readers can use in place of a file path.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct polars_reader_callbacks_t {
+    user: *const c_void,
+    read: ReadCallback,
+    seek: SeekCallback,
+    tell: TellCallback,
+}
+
+// Safety: the callbacks are only called from the thread calling the reader function, the host is
+// responsible for the user data when readers are used from multiple threads.
+unsafe impl Send for polars_reader_callbacks_t {}
+unsafe impl Sync for polars_reader_callbacks_t {}
+
+impl std::io::Read for polars_reader_callbacks_t {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = unsafe { (self.read)(self.user, buf.as_mut_ptr(), buf.len()) };
+        if n < 0 {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "user callback error",
+            ))
+        } else {
+            Ok(n as usize)
+        }
+    }
+}
+
+impl std::io::Seek for polars_reader_callbacks_t {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        let (offset, whence) = match pos {
+            std::io::SeekFrom::Start(offset) => (offset as i64, 0),
+            std::io::SeekFrom::Current(offset) => (offset, 1),
+            std::io::SeekFrom::End(offset) => (offset, 2),
+        };
+        let n = unsafe { (self.seek)(self.user, offset, whence) };
+        if n < 0 {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "user callback error",
+            ))
+        } else {
+            Ok(n as u64)
+        }
+    }
+
+    fn stream_position(&mut self) -> std::io::Result<u64> {
+        let n = unsafe { (self.tell)(self.user) };
+        if n < 0 {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "user callback error",
+            ))
+        } else {
+            Ok(n as u64)
+        }
+    }
+}
+
+impl MmapBytesReader for polars_reader_callbacks_t {}
+
+/// Writes the dataframe as a table to the callback, callback failures are ignored. Prefer
+/// `polars_dataframe_show_checked` which returns them.
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_show(
+    df: *mut polars_dataframe_t,
+    user: *const c_void,
+    callback: IOCallback,
+) {
+    let err = polars_dataframe_show_checked(df, user, callback);
+    if !err.is_null() {
+        polars_error_destroy(err);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_show_checked(
+    df: *mut polars_dataframe_t,
+    user: *const c_void,
+    callback: IOCallback,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!df.is_null());
+        let df = &(*df).inner;
+        let mut w = UserIOCallback(callback, user);
+        if let Err(err) = write!(w, "{df}") {
+            return make_error(err);
+        }
+        std::ptr::null()
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_get(
+    df: *mut polars_dataframe_t,
+    name: *const u8,
+    len: usize,
+    out: *mut *mut polars_series_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        let name = unsafe { std::slice::from_raw_parts(name, len) };
+        let name = match std::str::from_utf8(name) {
+            Ok(path) => path,
+            Err(err) => return make_error(err),
+        };
+
+        let df = &(*df).inner;
+        let mut series = match df.select_series(&[name]) {
+            Ok(series) => series,
+            Err(err) => return make_error(err),
+        };
+
+        let Some(series) = series.pop() else {
+            return make_error(polars_err!(ColumnNotFound: "dataframe has not column {name}"));
+        };
+
+        *out = series::make_series(series);
+
+        std::ptr::null()
+    })
+}
+
+/// Returns null on error, prefer `polars_dataframe_lazy_checked` which reports it.
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_lazy(
+    df: *mut polars_dataframe_t,
+) -> *mut polars_lazy_frame_t {
+    let mut out = std::ptr::null_mut();
+    let err = polars_dataframe_lazy_checked(df, &mut out);
+    if !err.is_null() {
+        polars_error_destroy(err);
+    }
+    out
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_lazy_checked(
+    df: *mut polars_dataframe_t,
+    out: *mut *mut polars_lazy_frame_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!df.is_null());
+        let df = &(*df).inner;
+        *out = Box::into_raw(Box::new(polars_lazy_frame_t {
+            inner: df.clone().lazy(),
+        }));
+        std::ptr::null()
+    })
+}
+
+#[repr(C)]
+pub enum polars_parallel_strategy_t {
+    PolarsParallelStrategyAuto,
+    PolarsParallelStrategyNone,
+    PolarsParallelStrategyColumns,
+    PolarsParallelStrategyRowGroups,
+}
+
+impl polars_parallel_strategy_t {
+    fn to_parallel_strategy(&self) -> ParallelStrategy {
+        use polars_parallel_strategy_t::*;
+        match self {
+            PolarsParallelStrategyAuto => ParallelStrategy::Auto,
+            PolarsParallelStrategyNone => ParallelStrategy::None,
+            PolarsParallelStrategyColumns => ParallelStrategy::Columns,
+            PolarsParallelStrategyRowGroups => ParallelStrategy::RowGroups,
+        }
+    }
+}
+
+/// Options used by `polars_lazy_frame_scan_parquet`, use `polars_parquet_scan_options_default`
+/// to get the default options.
+#[repr(C)]
+pub struct polars_parquet_scan_options_t {
+    /// Stop reading after this number of rows, `SIZE_MAX` reads all the rows.
+    n_rows: usize,
+    /// Name of the row count column, no row count column is added when empty.
+    row_count_name: *const u8,
+    row_count_name_len: usize,
+    row_count_offset: u32,
+    parallel: polars_parallel_strategy_t,
+    low_memory: bool,
+    /// Whether to add the `key=value` directories of the scanned files below the non-glob prefix
+    /// of their path as columns.
+    hive_partitioning: bool,
+}
+
+#[no_mangle]
+pub extern "C" fn polars_parquet_scan_options_default() -> polars_parquet_scan_options_t {
+    polars_parquet_scan_options_t {
+        n_rows: usize::MAX,
+        row_count_name: std::ptr::null(),
+        row_count_name_len: 0,
+        row_count_offset: 0,
+        parallel: polars_parallel_strategy_t::PolarsParallelStrategyAuto,
+        low_memory: false,
+        hive_partitioning: false,
+    }
+}
+
+/// Creates a LazyFrame scanning the parquet file at `path`, the path can also be a glob pattern
+/// in which case all the matching files are scanned.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_scan_parquet(
+    path: *const u8,
+    pathlen: usize,
+    options: *const polars_parquet_scan_options_t,
+    out: *mut *mut polars_lazy_frame_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!options.is_null());
+        let options = &*options;
+
+        let path = std::slice::from_raw_parts(path, pathlen);
+        let path = match std::str::from_utf8(path) {
+            Ok(path) => path,
+            Err(err) => return make_error(err),
+        };
+
+        let row_count = match make_row_count(
+            options.row_count_name,
+            options.row_count_name_len,
+            options.row_count_offset,
+        ) {
+            Ok(row_count) => row_count,
+            Err(err) => return make_error(err),
+        };
+
+        let args = ScanArgsParquet {
+            n_rows: make_optional_size(options.n_rows),
+            row_count,
+            parallel: options.parallel.to_parallel_strategy(),
+            low_memory: options.low_memory,
+            ..Default::default()
+        };
+
+        let df = if options.hive_partitioning {
+            scan_parquet_files(&[path.to_owned()], args, true)
+        } else {
+            LazyFrame::scan_parquet(path, args)
+        };
+        let df = match df {
+            Ok(df) => df,
+            Err(err) => return make_error(err),
+        };
+        *out = Box::into_raw(Box::new(polars_lazy_frame_t { inner: df }));
+
+        std::ptr::null()
+    })
+}
+
+/// Creates a LazyFrame scanning the union of the parquet files in `paths`, each path can also be
+/// a glob pattern. When `hive_partitioning` is set in the options, the `key=value` directories of
+/// each file below the non-glob prefix of its pattern are added as typed columns, and the files
+/// whose partition values do not match the filters applied to the returned LazyFrame are
+/// skipped.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_scan_parquet_files(
+    paths: *const *const u8,
+    pathlens: *const usize,
+    npaths: usize,
+    options: *const polars_parquet_scan_options_t,
+    out: *mut *mut polars_lazy_frame_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!options.is_null());
+        let options = &*options;
+
+        let paths = match make_strings(paths, pathlens, npaths) {
+            Ok(paths) => paths,
+            Err(err) => return make_error(err),
+        };
+
+        let row_count = match make_row_count(
+            options.row_count_name,
+            options.row_count_name_len,
+            options.row_count_offset,
+        ) {
+            Ok(row_count) => row_count,
+            Err(err) => return make_error(err),
+        };
+
+        let args = ScanArgsParquet {
+            n_rows: make_optional_size(options.n_rows),
+            row_count,
+            parallel: options.parallel.to_parallel_strategy(),
+            low_memory: options.low_memory,
+            ..Default::default()
+        };
+
+        let df = match scan_parquet_files(&paths, args, options.hive_partitioning) {
+            Ok(df) => df,
+            Err(err) => return make_error(err),
+        };
+        *out = Box::into_raw(Box::new(polars_lazy_frame_t { inner: df }));
+
+        std::ptr::null()
+    })
+}
+
+/// Scans the union of the files in `paths` with `scan_file`, each path can also be a glob
+/// pattern. The row limit and the row count are applied on the union, as polars does for globs,
+/// so `scan_file` must not apply them.
+///
+/// The `key=value` directories of the files below the non-glob prefix of their pattern are added
+/// as typed columns when `hive_partitioning` is set. The filters applied to the resulting
+/// LazyFrame are then evaluated on those columns before any file is opened, so that the files of
+/// the partitions which cannot match are skipped.
+fn scan_files(
+    paths: &[String],
+    hive_partitioning: bool,
+    n_rows: Option<usize>,
+    row_count: Option<polars::io::RowCount>,
+    rechunk: bool,
+    scan_file: impl Fn(&std::path::Path) -> PolarsResult<LazyFrame> + Send + Sync + 'static,
+) -> PolarsResult<LazyFrame> {
+    let (paths, relative): (Vec<_>, Vec<_>) = hive::expand_paths(paths)?.into_iter().unzip();
+    polars_ensure!(!paths.is_empty(), ComputeError: "no files to scan");
+
+    let mut lf = if hive_partitioning {
+        let partitions = hive::partitions_frame(&relative)?;
+        hive::scan_partitioned(paths, partitions, rechunk, Box::new(scan_file))?
+    } else {
+        let indices: Vec<_> = (0..paths.len()).collect();
+        hive::scan_union(&paths, &DataFrame::empty(), &indices, rechunk, &scan_file)?
+    };
+
+    if let Some(n_rows) = n_rows {
+        lf = lf.slice(0, n_rows as IdxSize);
+    }
+    if let Some(rc) = &row_count {
+        lf = lf.with_row_count(&rc.name, Some(rc.offset));
+    }
+
+    Ok(lf)
+}
+
+/// Scans the parquet files of `paths` as `scan_files` does.
+fn scan_parquet_files(
+    paths: &[String],
+    args: ScanArgsParquet,
+    hive_partitioning: bool,
+) -> PolarsResult<LazyFrame> {
+    let file_args = ScanArgsParquet {
+        n_rows: None,
+        row_count: None,
+        rechunk: false,
+        ..args.clone()
+    };
+    scan_files(
+        paths,
+        hive_partitioning,
+        args.n_rows,
+        args.row_count,
+        args.rechunk,
+        move |path| LazyFrame::scan_parquet(path, file_args.clone()),
+    )
+}
+
+#[repr(C)]
+pub enum polars_csv_encoding_t {
+    PolarsCsvEncodingUtf8,
+    /// Invalid utf8 bytes are replaced with the replacement character.
+    PolarsCsvEncodingLossyUtf8,
+}
+
+/// Options used by `polars_lazy_frame_scan_csv`, use `polars_csv_scan_options_default` to get
+/// the default options.
+#[repr(C)]
+pub struct polars_csv_scan_options_t {
+    separator: u8,
+    has_header: bool,
+    /// The quote character, 0 disables quoting.
+    quote_char: u8,
+    /// Lines starting with this character are skipped, 0 disables comments.
+    comment_char: u8,
+    skip_rows: usize,
+    /// Stop reading after this number of rows, `SIZE_MAX` reads all the rows.
+    n_rows: usize,
+    /// Number of rows used to infer the schema, `SIZE_MAX` uses the whole file.
+    infer_schema_length: usize,
+    /// Overrides the inferred dtypes of the named columns.
+    dtypes_names: *const *const u8,
+    dtypes_names_lens: *const usize,
+    dtypes: *const polars_value_type_t,
+    n_dtypes: usize,
+    encoding: polars_csv_encoding_t,
+    ignore_errors: bool,
+    low_memory: bool,
+    /// Name of the row count column, no row count column is added when empty.
+    row_count_name: *const u8,
+    row_count_name_len: usize,
+    row_count_offset: u32,
+    /// Whether to add the `key=value` directories of the scanned files below the non-glob prefix
+    /// of their path as columns.
+    hive_partitioning: bool,
+}
+
+#[no_mangle]
+pub extern "C" fn polars_csv_scan_options_default() -> polars_csv_scan_options_t {
+    polars_csv_scan_options_t {
+        separator: b',',
+        has_header: true,
+        quote_char: b'"',
+        comment_char: 0,
+        skip_rows: 0,
+        n_rows: usize::MAX,
+        infer_schema_length: 100,
+        dtypes_names: std::ptr::null(),
+        dtypes_names_lens: std::ptr::null(),
+        dtypes: std::ptr::null(),
+        n_dtypes: 0,
+        encoding: polars_csv_encoding_t::PolarsCsvEncodingUtf8,
+        ignore_errors: false,
+        low_memory: false,
+        row_count_name: std::ptr::null(),
+        row_count_name_len: 0,
+        row_count_offset: 0,
+        hive_partitioning: false,
+    }
+}
+
+/// Returns a reader of the csv file at `path` configured by `options`, without the row limit
+/// and the row count.
+fn make_lazy_csv_reader<'a>(
+    path: impl AsRef<std::path::Path>,
+    options: &polars_csv_scan_options_t,
+    dtypes: Option<&'a Schema>,
+) -> LazyCsvReader<'a> {
+    let encoding = match options.encoding {
+        polars_csv_encoding_t::PolarsCsvEncodingUtf8 => CsvEncoding::Utf8,
+        polars_csv_encoding_t::PolarsCsvEncodingLossyUtf8 => CsvEncoding::LossyUtf8,
+    };
+
+    LazyCsvReader::new(path)
+        .with_delimiter(options.separator)
+        .has_header(options.has_header)
+        .with_quote_char(make_optional_char(options.quote_char))
+        .with_comment_char(make_optional_char(options.comment_char))
+        .with_skip_rows(options.skip_rows)
+        .with_infer_schema_length(make_optional_size(options.infer_schema_length))
+        .with_dtype_overwrite(dtypes)
+        .with_encoding(encoding)
+        .with_ignore_errors(options.ignore_errors)
+        .low_memory(options.low_memory)
+}
+
+/// Creates a LazyFrame scanning the csv files of `paths` as configured by `options`, `dtypes`
+/// and `row_count` are parsed from the options.
+fn scan_csv_files(
+    paths: &[String],
+    options: &polars_csv_scan_options_t,
+    dtypes: Option<&Schema>,
+    row_count: Option<polars::io::RowCount>,
+) -> PolarsResult<LazyFrame> {
+    let n_rows = make_optional_size(options.n_rows);
+    if let ([path], false) = (paths, options.hive_partitioning) {
+        return make_lazy_csv_reader(path, options, dtypes)
+            .with_n_rows(n_rows)
+            .with_row_count(row_count)
+            .finish();
+    }
+
+    // The reader is copied for each file since the options are borrowed from the caller.
+    let reader = make_lazy_csv_reader("", options, None).with_rechunk(false);
+    let dtypes = dtypes.cloned();
+    scan_files(
+        paths,
+        options.hive_partitioning,
+        n_rows,
+        row_count,
+        true,
+        move |path| {
+            let reader: LazyCsvReader = reader.clone();
+            reader
+                .with_path(path.to_owned())
+                .with_dtype_overwrite(dtypes.as_ref())
+                .finish()
+        },
+    )
+}
+
+/// Creates a LazyFrame scanning the csv file at `path`, the path can also be a glob pattern
+/// in which case all the matching files are scanned.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_scan_csv(
+    path: *const u8,
+    pathlen: usize,
+    options: *const polars_csv_scan_options_t,
+    out: *mut *mut polars_lazy_frame_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!options.is_null());
+        let options = &*options;
+
+        let path = std::slice::from_raw_parts(path, pathlen);
+        let path = match std::str::from_utf8(path) {
+            Ok(path) => path,
+            Err(err) => return make_error(err),
+        };
+
+        let dtypes = match make_schema(
+            options.dtypes_names,
+            options.dtypes_names_lens,
+            options.dtypes,
+            options.n_dtypes,
+        ) {
+            Ok(schema) => schema,
+            Err(err) => return make_error(err),
+        };
+
+        let row_count = match make_row_count(
+            options.row_count_name,
+            options.row_count_name_len,
+            options.row_count_offset,
+        ) {
+            Ok(row_count) => row_count,
+            Err(err) => return make_error(err),
+        };
+
+        let df = match scan_csv_files(&[path.to_owned()], options, dtypes.as_ref(), row_count) {
+            Ok(df) => df,
+            Err(err) => return make_error(err),
+        };
+        *out = Box::into_raw(Box::new(polars_lazy_frame_t { inner: df }));
+
+        std::ptr::null()
+    })
+}
+
+/// Creates a LazyFrame scanning the union of the csv files in `paths`, the paths and the
+/// hive partitions are handled as in `polars_lazy_frame_scan_parquet_files`.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_scan_csv_files(
+    paths: *const *const u8,
+    pathlens: *const usize,
+    npaths: usize,
+    options: *const polars_csv_scan_options_t,
+    out: *mut *mut polars_lazy_frame_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!options.is_null());
+        let options = &*options;
+
+        let paths = match make_strings(paths, pathlens, npaths) {
+            Ok(paths) => paths,
+            Err(err) => return make_error(err),
+        };
+
+        let dtypes = match make_schema(
+            options.dtypes_names,
+            options.dtypes_names_lens,
+            options.dtypes,
+            options.n_dtypes,
+        ) {
+            Ok(schema) => schema,
+            Err(err) => return make_error(err),
+        };
+
+        let row_count = match make_row_count(
+            options.row_count_name,
+            options.row_count_name_len,
+            options.row_count_offset,
+        ) {
+            Ok(row_count) => row_count,
+            Err(err) => return make_error(err),
+        };
+
+        let df = match scan_csv_files(&paths, options, dtypes.as_ref(), row_count) {
+            Ok(df) => df,
+            Err(err) => return make_error(err),
+        };
+        *out = Box::into_raw(Box::new(polars_lazy_frame_t { inner: df }));
+
+        std::ptr::null()
+    })
+}
+
+/// Options used by `polars_lazy_frame_scan_ndjson`, use `polars_ndjson_scan_options_default` to
+/// get the default options.
+#[repr(C)]
+pub struct polars_ndjson_scan_options_t {
+    /// Stop reading after this number of rows, `SIZE_MAX` reads all the rows.
+    n_rows: usize,
+    /// Number of rows used to infer the schema, `SIZE_MAX` uses the whole file.
+    infer_schema_length: usize,
+    low_memory: bool,
+    /// Name of the row count column, no row count column is added when empty.
+    row_count_name: *const u8,
+    row_count_name_len: usize,
+    row_count_offset: u32,
+    /// Whether to add the `key=value` directories of the scanned files below the non-glob prefix
+    /// of their path as columns.
+    hive_partitioning: bool,
+}
+
+#[no_mangle]
+pub extern "C" fn polars_ndjson_scan_options_default() -> polars_ndjson_scan_options_t {
+    polars_ndjson_scan_options_t {
+        n_rows: usize::MAX,
+        infer_schema_length: 100,
+        low_memory: false,
+        row_count_name: std::ptr::null(),
+        row_count_name_len: 0,
+        row_count_offset: 0,
+        hive_partitioning: false,
+    }
+}
+
+/// Creates a LazyFrame scanning the newline-delimited JSON files of `paths` as configured by
+/// `options`, `row_count` is parsed from the options.
+fn scan_ndjson_files(
+    paths: &[String],
+    options: &polars_ndjson_scan_options_t,
+    row_count: Option<polars::io::RowCount>,
+) -> PolarsResult<LazyFrame> {
+    let n_rows = make_optional_size(options.n_rows);
+    let reader = LazyJsonLineReader::new("")
+        .with_infer_schema_length(make_optional_size(options.infer_schema_length))
+        .low_memory(options.low_memory);
+
+    if let ([path], false) = (paths, options.hive_partitioning) {
+        return reader
+            .with_path(path.into())
+            .with_n_rows(n_rows)
+            .with_row_count(row_count)
+            .finish();
+    }
+
+    let reader = reader.with_rechunk(false);
+    scan_files(
+        paths,
+        options.hive_partitioning,
+        n_rows,
+        row_count,
+        true,
+        move |path| reader.clone().with_path(path.to_owned()).finish(),
+    )
+}
+
+/// Creates a LazyFrame scanning the newline-delimited JSON file at `path`, the path can also be a
+/// glob pattern in which case all the matching files are scanned.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_scan_ndjson(
+    path: *const u8,
+    pathlen: usize,
+    options: *const polars_ndjson_scan_options_t,
+    out: *mut *mut polars_lazy_frame_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!options.is_null());
+        let options = &*options;
+
+        let path = std::slice::from_raw_parts(path, pathlen);
+        let path = match std::str::from_utf8(path) {
+            Ok(path) => path,
+            Err(err) => return make_error(err),
+        };
+
+        let row_count = match make_row_count(
+            options.row_count_name,
+            options.row_count_name_len,
+            options.row_count_offset,
+        ) {
+            Ok(row_count) => row_count,
+            Err(err) => return make_error(err),
+        };
+
+        let df = match scan_ndjson_files(&[path.to_owned()], options, row_count) {
+            Ok(df) => df,
+            Err(err) => return make_error(err),
+        };
+        *out = Box::into_raw(Box::new(polars_lazy_frame_t { inner: df }));
+
+        std::ptr::null()
+    })
+}
+
+/// Creates a LazyFrame scanning the union of the newline-delimited JSON files in `paths`, the
+/// paths and the hive partitions are handled as in `polars_lazy_frame_scan_parquet_files`.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_scan_ndjson_files(
+    paths: *const *const u8,
+    pathlens: *const usize,
+    npaths: usize,
+    options: *const polars_ndjson_scan_options_t,
+    out: *mut *mut polars_lazy_frame_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!options.is_null());
+        let options = &*options;
+
+        let paths = match make_strings(paths, pathlens, npaths) {
+            Ok(paths) => paths,
+            Err(err) => return make_error(err),
+        };
+
+        let row_count = match make_row_count(
+            options.row_count_name,
+            options.row_count_name_len,
+            options.row_count_offset,
+        ) {
+            Ok(row_count) => row_count,
+            Err(err) => return make_error(err),
+        };
+
+        let df = match scan_ndjson_files(&paths, options, row_count) {
+            Ok(df) => df,
+            Err(err) => return make_error(err),
+        };
+        *out = Box::into_raw(Box::new(polars_lazy_frame_t { inner: df }));
+
+        std::ptr::null()
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_destroy(df: *mut polars_lazy_frame_t) {
+    catch_panic(|| {
+        assert!(!df.is_null());
+        let _ = Box::from_raw(df);
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_clone(
+    df: *mut polars_lazy_frame_t,
+) -> *mut polars_lazy_frame_t {
+    catch_panic(|| {
+        assert!(!df.is_null());
+        Box::into_raw(Box::new(polars_lazy_frame_t {
+            inner: (*df).inner.clone(),
+        }))
+    })
+}
+
+/// Sorts the LazyFrame in place, errors are ignored. Prefer `polars_lazy_frame_sort_checked`
+/// which reports them.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_sort(
+    df: *mut polars_lazy_frame_t,
+    exprs: *const *const polars_expr_t,
+    nexprs: usize,
+    descending: *const bool,
+    nulls_last: bool,
+    maintain_order: bool,
+) {
+    let err =
+        polars_lazy_frame_sort_checked(df, exprs, nexprs, descending, nulls_last, maintain_order);
+    if !err.is_null() {
+        polars_error_destroy(err);
+    }
+}
+
+/// Sorts the LazyFrame in place by `exprs`, `descending` has one flag per expression.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_sort_checked(
+    df: *mut polars_lazy_frame_t,
+    exprs: *const *const polars_expr_t,
+    nexprs: usize,
+    descending: *const bool,
+    nulls_last: bool,
+    maintain_order: bool,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!df.is_null());
+        let exprs = match make_exprs(exprs, nexprs) {
+            Ok(exprs) => exprs,
+            Err(err) => return make_error(err),
+        };
+        let descending = if nexprs == 0 {
+            &[]
+        } else {
+            assert!(!descending.is_null());
+            std::slice::from_raw_parts(descending, nexprs)
+        };
+        let df = &mut *df;
+        df.inner = df
+            .inner
+            .clone()
+            .sort_by_exprs(&exprs, descending, nulls_last, maintain_order);
+        std::ptr::null()
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_concat(
+    lfs: *const *mut polars_lazy_frame_t,
+    n: usize,
+    out: *mut *mut polars_lazy_frame_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        let frames: Vec<LazyFrame> = (1..n).map(|i| (**lfs.add(i)).inner.clone()).collect();
+
+        let df = match concat(&frames, UnionArgs::default()) {
+            Ok(df) => df,
+            Err(err) => return make_error(err),
+        };
+        *out = Box::into_raw(Box::new(polars_lazy_frame_t { inner: df }));
+
+        std::ptr::null()
+    })
+}
+
+/// Errors are ignored, prefer `polars_lazy_frame_with_columns_checked` which reports them.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_with_columns(
+    df: *mut polars_lazy_frame_t,
+    exprs: *const *const polars_expr_t,
+    nexprs: usize,
+) {
+    let err = polars_lazy_frame_with_columns_checked(df, exprs, nexprs);
+    if !err.is_null() {
+        polars_error_destroy(err);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_with_columns_checked(
+    df: *mut polars_lazy_frame_t,
+    exprs: *const *const polars_expr_t,
+    nexprs: usize,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!df.is_null());
+        let exprs = match make_exprs(exprs, nexprs) {
+            Ok(exprs) => exprs,
+            Err(err) => return make_error(err),
+        };
+        let df = &mut *df;
+        df.inner = df.inner.clone().with_columns(&exprs);
+        std::ptr::null()
+    })
+}
+
+/// Errors are ignored, prefer `polars_lazy_frame_select_checked` which reports them.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_select(
+    df: *mut polars_lazy_frame_t,
+    exprs: *const *const polars_expr_t,
+    nexprs: usize,
+) {
+    let err = polars_lazy_frame_select_checked(df, exprs, nexprs);
+    if !err.is_null() {
+        polars_error_destroy(err);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_select_checked(
+    df: *mut polars_lazy_frame_t,
+    exprs: *const *const polars_expr_t,
+    nexprs: usize,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!df.is_null());
+        let exprs = match make_exprs(exprs, nexprs) {
+            Ok(exprs) => exprs,
+            Err(err) => return make_error(err),
+        };
+        let df = &mut *df;
+        df.inner = df.inner.clone().select(&exprs);
+        std::ptr::null()
+    })
+}
+
+/// Errors are ignored, prefer `polars_lazy_frame_filter_checked` which reports them.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_filter(
+    df: *mut polars_lazy_frame_t,
+    expr: *const polars_expr_t,
+) {
+    let err = polars_lazy_frame_filter_checked(df, expr);
+    if !err.is_null() {
+        polars_error_destroy(err);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_filter_checked(
+    df: *mut polars_lazy_frame_t,
+    expr: *const polars_expr_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!df.is_null());
+        assert!(!expr.is_null());
+        let df = &mut *df;
+        df.inner = df.inner.clone().filter((*expr).inner.clone()); // NOTE: we clone the expr here, can we assume
+                                                                   // that the function takes ownership of it?
+        std::ptr::null()
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_collect(
+    df: *mut polars_lazy_frame_t,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        let df = (*df).inner.clone();
+        *out = make_dataframe(match df.collect() {
+            Ok(value) => value,
+            Err(err) => return make_error(err),
+        });
+        std::ptr::null()
+    })
+}
+
+/// Collects the LazyFrame and exports the result to `out` in the same way as
+/// `polars_dataframe_export_carrow_stream`.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_collect_carrow_stream(
+    df: *mut polars_lazy_frame_t,
+    out: *mut ArrowArrayStream,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!out.is_null());
+        let df = (*df).inner.clone();
+        *out = make_carrow_stream(match df.collect() {
+            Ok(value) => value,
+            Err(err) => return make_error(err),
+        });
+        std::ptr::null()
+    })
+}
+
+/// Runs the query on the streaming engine and writes the result to the parquet file at `path`
+/// without materializing it in memory. Fails if the query cannot be run in a streaming fashion.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_sink_parquet(
+    df: *mut polars_lazy_frame_t,
+    path: *const u8,
+    pathlen: usize,
+    options: *const polars_parquet_write_options_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!options.is_null());
+
+        let path = std::slice::from_raw_parts(path, pathlen);
+        let path = match std::str::from_utf8(path) {
+            Ok(path) => path,
+            Err(err) => return make_error(err),
+        };
+
+        let options = match (*options).to_write_options() {
+            Ok(options) => options,
+            Err(err) => return make_error(err),
+        };
+
+        let df = (*df).inner.clone();
+        if let Err(err) = df.sink_parquet(path.into(), options) {
+            return make_error(err);
+        }
+
+        std::ptr::null()
+    })
+}
+
+/// Runs the query on the streaming engine and writes the result to the IPC file at `path`
+/// without materializing it in memory. Fails if the query cannot be run in a streaming fashion.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_sink_ipc(
+    df: *mut polars_lazy_frame_t,
+    path: *const u8,
+    pathlen: usize,
+    compression: polars_ipc_compression_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        let path = std::slice::from_raw_parts(path, pathlen);
+        let path = match std::str::from_utf8(path) {
+            Ok(path) => path,
+            Err(err) => return make_error(err),
+        };
+
+        let options = IpcWriterOptions {
+            compression: compression.to_ipc_compression(),
+            maintain_order: true,
+        };
+
+        let df = (*df).inner.clone();
+        if let Err(err) = df.sink_ipc(path.into(), options) {
+            return make_error(err);
+        }
+
+        std::ptr::null()
+    })
+}
+
+/// Returns null on error, prefer `polars_lazy_frame_group_by_checked` which reports it.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_group_by(
+    df: *mut polars_lazy_frame_t,
+    exprs: *const *const polars_expr_t,
+    nexprs: usize,
 ) -> *mut polars_lazy_group_by_t {
-    let exprs: Vec<Expr> = std::slice::from_raw_parts(exprs, nexprs)
-        .iter()
-        .map(|expr| (**expr).inner.clone())
-        .collect();
-    let gb = (*df).inner.clone().groupby(&exprs);
-    Box::into_raw(Box::new(polars_lazy_group_by_t { inner: gb }))
+    let mut out = std::ptr::null_mut();
+    let err = polars_lazy_frame_group_by_checked(df, exprs, nexprs, &mut out);
+    if !err.is_null() {
+        polars_error_destroy(err);
+    }
+    out
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_group_by_checked(
+    df: *mut polars_lazy_frame_t,
+    exprs: *const *const polars_expr_t,
+    nexprs: usize,
+    out: *mut *mut polars_lazy_group_by_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!df.is_null());
+        let exprs = match make_exprs(exprs, nexprs) {
+            Ok(exprs) => exprs,
+            Err(err) => return make_error(err),
+        };
+        let gb = (*df).inner.clone().groupby(&exprs);
+        *out = Box::into_raw(Box::new(polars_lazy_group_by_t { inner: gb }));
+        std::ptr::null()
+    })
 }
 
+/// Returns null on error, prefer `polars_lazy_frame_join_inner_checked` which reports it.
 #[no_mangle]
 pub unsafe extern "C" fn polars_lazy_frame_join_inner(
     a: *mut polars_lazy_frame_t,
@@ -419,22 +2507,59 @@ pub unsafe extern "C" fn polars_lazy_frame_join_inner(
     exprs_b: *const *const polars_expr_t,
     exprs_b_len: usize,
 ) -> *mut polars_lazy_frame_t {
-    let exprs_a: Vec<Expr> = std::slice::from_raw_parts(exprs_a, exprs_a_len)
-        .iter()
-        .map(|expr| (**expr).inner.clone())
-        .collect();
-    let exprs_b: Vec<Expr> = std::slice::from_raw_parts(exprs_b, exprs_b_len)
-        .iter()
-        .map(|expr| (**expr).inner.clone())
-        .collect();
-    let df = LazyFrame::join(
-        (*a).inner.clone(),
-        (*b).inner.clone(),
+    let mut out = std::ptr::null_mut();
+    let err = polars_lazy_frame_join_inner_checked(
+        a,
+        b,
         exprs_a,
+        exprs_a_len,
         exprs_b,
-        JoinArgs::new(JoinType::Inner),
+        exprs_b_len,
+        &mut out,
     );
-    Box::into_raw(Box::new(polars_lazy_frame_t { inner: df }))
+    if !err.is_null() {
+        polars_error_destroy(err);
+    }
+    out
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_join_inner_checked(
+    a: *mut polars_lazy_frame_t,
+    b: *mut polars_lazy_frame_t,
+    exprs_a: *const *const polars_expr_t,
+    exprs_a_len: usize,
+    exprs_b: *const *const polars_expr_t,
+    exprs_b_len: usize,
+    out: *mut *mut polars_lazy_frame_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!a.is_null());
+        assert!(!b.is_null());
+        let exprs_a = match make_exprs(exprs_a, exprs_a_len) {
+            Ok(exprs) => exprs,
+            Err(err) => return make_error(err),
+        };
+        let exprs_b = match make_exprs(exprs_b, exprs_b_len) {
+            Ok(exprs) => exprs,
+            Err(err) => return make_error(err),
+        };
+        if exprs_a.len() != exprs_b.len() {
+            return make_error(polars_err!(
+                ShapeMismatch: "cannot join on {} expressions with {} expressions",
+                exprs_a.len(), exprs_b.len()
+            ));
+        }
+        let df = LazyFrame::join(
+            (*a).inner.clone(),
+            (*b).inner.clone(),
+            exprs_a,
+            exprs_b,
+            JoinArgs::new(JoinType::Inner),
+        );
+        *out = Box::into_raw(Box::new(polars_lazy_frame_t { inner: df }));
+        std::ptr::null()
+    })
 }
 
 #[no_mangle]
@@ -443,31 +2568,55 @@ pub unsafe extern "C" fn polars_lazy_frame_fetch(
     n: usize,
     out: *mut *mut polars_dataframe_t,
 ) -> *const polars_error_t {
-    let df = (*df).inner.clone();
-    *out = make_dataframe(match df.fetch(n) {
-        Ok(value) => value,
-        Err(err) => return make_error(err),
-    });
-    std::ptr::null()
+    catch_panic(|| {
+        let df = (*df).inner.clone();
+        *out = make_dataframe(match df.fetch(n) {
+            Ok(value) => value,
+            Err(err) => return make_error(err),
+        });
+        std::ptr::null()
+    })
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_lazy_group_by_destroy(gb: *const polars_lazy_group_by_t) {
-    assert!(!gb.is_null());
-    let _ = Box::from_raw(gb.cast_mut());
+    catch_panic(|| {
+        assert!(!gb.is_null());
+        let _ = Box::from_raw(gb.cast_mut());
+    })
 }
 
+/// Returns null on error, prefer `polars_lazy_group_by_agg_checked` which reports it.
 #[no_mangle]
 pub unsafe extern "C" fn polars_lazy_group_by_agg(
     gb: *mut polars_lazy_group_by_t,
     exprs: *const *const polars_expr_t,
     nexprs: usize,
 ) -> *mut polars_lazy_frame_t {
-    let exprs: Vec<Expr> = std::slice::from_raw_parts(exprs, nexprs)
-        .iter()
-        .map(|expr| (**expr).inner.clone())
-        .collect();
-    Box::into_raw(Box::new(polars_lazy_frame_t {
-        inner: (*gb).inner.clone().agg(&exprs),
-    }))
+    let mut out = std::ptr::null_mut();
+    let err = polars_lazy_group_by_agg_checked(gb, exprs, nexprs, &mut out);
+    if !err.is_null() {
+        polars_error_destroy(err);
+    }
+    out
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_group_by_agg_checked(
+    gb: *mut polars_lazy_group_by_t,
+    exprs: *const *const polars_expr_t,
+    nexprs: usize,
+    out: *mut *mut polars_lazy_frame_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!gb.is_null());
+        let exprs = match make_exprs(exprs, nexprs) {
+            Ok(exprs) => exprs,
+            Err(err) => return make_error(err),
+        };
+        *out = Box::into_raw(Box::new(polars_lazy_frame_t {
+            inner: (*gb).inner.clone().agg(&exprs),
+        }));
+        std::ptr::null()
+    })
 }
@@ -0,0 +1,383 @@
+use polars_core::utils::arrow::io::parquet::read::{
+    self as parquet_read, statistics, FileMetaData,
+};
+
+use crate::*;
+
+pub struct polars_parquet_metadata_t {
+    inner: FileMetaData,
+}
+
+fn make_parquet_metadata(metadata: FileMetaData) -> *mut polars_parquet_metadata_t {
+    Box::into_raw(Box::new(polars_parquet_metadata_t { inner: metadata }))
+}
+
+/// Reads the metadata of the parquet file at `path` without reading its data.
+#[no_mangle]
+pub unsafe extern "C" fn polars_parquet_metadata_read(
+    path: *const u8,
+    pathlen: usize,
+    out: *mut *mut polars_parquet_metadata_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        let path = std::slice::from_raw_parts(path, pathlen);
+        let path = match std::str::from_utf8(path) {
+            Ok(path) => path,
+            Err(err) => return make_error(err),
+        };
+
+        let mut file = match std::fs::OpenOptions::new().read(true).open(path) {
+            Ok(file) => file,
+            Err(err) => return make_error(err),
+        };
+
+        match parquet_read::read_metadata(&mut file) {
+            Ok(metadata) => *out = make_parquet_metadata(metadata),
+            Err(err) => return make_error(err),
+        }
+
+        std::ptr::null()
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_parquet_metadata_read_from_callbacks(
+    mut callbacks: polars_reader_callbacks_t,
+    out: *mut *mut polars_parquet_metadata_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        match parquet_read::read_metadata(&mut callbacks) {
+            Ok(metadata) => *out = make_parquet_metadata(metadata),
+            Err(err) => return make_error(err),
+        }
+
+        std::ptr::null()
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_parquet_metadata_destroy(metadata: *mut polars_parquet_metadata_t) {
+    catch_panic(|| {
+        assert!(!metadata.is_null());
+        let _ = Box::from_raw(metadata);
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_parquet_metadata_num_rows(
+    metadata: *mut polars_parquet_metadata_t,
+) -> usize {
+    catch_panic(|| {
+        assert!(!metadata.is_null());
+        (*metadata).inner.num_rows
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_parquet_metadata_num_row_groups(
+    metadata: *mut polars_parquet_metadata_t,
+) -> usize {
+    catch_panic(|| {
+        assert!(!metadata.is_null());
+        (*metadata).inner.row_groups.len()
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_parquet_metadata_row_group_num_rows(
+    metadata: *mut polars_parquet_metadata_t,
+    index: usize,
+    out: *mut usize,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!metadata.is_null());
+        let metadata = &*metadata;
+        let Some(row_group) = metadata.inner.row_groups.get(index) else {
+            return make_error_with_kind(
+                polars_error_kind_t::PolarsErrorKindOutOfBounds,
+                format!("invalid row group index {index}"),
+            );
+        };
+        *out = row_group.num_rows();
+        std::ptr::null()
+    })
+}
+
+/// Returns the total uncompressed size in bytes of the row group at `index`.
+#[no_mangle]
+pub unsafe extern "C" fn polars_parquet_metadata_row_group_byte_size(
+    metadata: *mut polars_parquet_metadata_t,
+    index: usize,
+    out: *mut usize,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!metadata.is_null());
+        let metadata = &*metadata;
+        let Some(row_group) = metadata.inner.row_groups.get(index) else {
+            return make_error_with_kind(
+                polars_error_kind_t::PolarsErrorKindOutOfBounds,
+                format!("invalid row group index {index}"),
+            );
+        };
+        *out = row_group.total_byte_size();
+        std::ptr::null()
+    })
+}
+
+/// Writes an ArrowSchema describing the file's schema according to Arrow C Data interface,
+/// the schema is a struct whose fields are the columns as in `polars_dataframe_schema`.
+#[no_mangle]
+pub unsafe extern "C" fn polars_parquet_metadata_schema(
+    metadata: *mut polars_parquet_metadata_t,
+    out: *mut ArrowSchema,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!metadata.is_null());
+        let schema = match parquet_read::infer_schema(&(*metadata).inner) {
+            Ok(schema) => schema,
+            Err(err) => return make_error(err),
+        };
+        let structfield = arrow::datatypes::Field::new(
+            "polars.dataframe",
+            arrow::datatypes::DataType::Struct(schema.fields),
+            false,
+        );
+        *out = ffi::export_field_to_c(&structfield);
+        std::ptr::null()
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_parquet_metadata_num_key_values(
+    metadata: *mut polars_parquet_metadata_t,
+) -> usize {
+    catch_panic(|| {
+        assert!(!metadata.is_null());
+        (*metadata)
+            .inner
+            .key_value_metadata()
+            .as_ref()
+            .map_or(0, |kv| kv.len())
+    })
+}
+
+/// Returns the key and value of the key-value metadata entry at `index`, the value is set to
+/// null with a length of 0 when the entry has no value. The returned strings are owned by the
+/// metadata.
+#[no_mangle]
+pub unsafe extern "C" fn polars_parquet_metadata_key_value(
+    metadata: *mut polars_parquet_metadata_t,
+    index: usize,
+    key: *mut *const u8,
+    keylen: *mut usize,
+    value: *mut *const u8,
+    valuelen: *mut usize,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!metadata.is_null());
+        let Some(kv) = (*metadata)
+            .inner
+            .key_value_metadata()
+            .as_ref()
+            .and_then(|kv| kv.get(index))
+        else {
+            return make_error_with_kind(
+                polars_error_kind_t::PolarsErrorKindOutOfBounds,
+                format!("invalid key-value index {index}"),
+            );
+        };
+
+        *key = kv.key.as_ptr();
+        *keylen = kv.key.len();
+        match &kv.value {
+            Some(v) => {
+                *value = v.as_ptr();
+                *valuelen = v.len();
+            }
+            None => {
+                *value = std::ptr::null();
+                *valuelen = 0;
+            }
+        }
+
+        std::ptr::null()
+    })
+}
+
+/// Returns the statistics of the column `name` as a DataFrame with the columns `null_count`,
+/// `distinct_count`, `min` and `max` and one row per row group. Values are null when the
+/// statistic was not written.
+#[no_mangle]
+pub unsafe extern "C" fn polars_parquet_metadata_statistics(
+    metadata: *mut polars_parquet_metadata_t,
+    name: *const u8,
+    len: usize,
+    out: *mut *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!metadata.is_null());
+        let name = match std::str::from_utf8(std::slice::from_raw_parts(name, len)) {
+            Ok(name) => name,
+            Err(err) => return make_error(err),
+        };
+
+        let metadata = &(*metadata).inner;
+        let schema = match parquet_read::infer_schema(metadata) {
+            Ok(schema) => schema,
+            Err(err) => return make_error(err),
+        };
+        let Some(field) = schema.fields.iter().find(|field| field.name == name) else {
+            return make_error(polars_err!(ColumnNotFound: "parquet file has no column {name}"));
+        };
+
+        let stats = match statistics::deserialize(field, &metadata.row_groups) {
+            Ok(stats) => stats,
+            Err(err) => return make_error(err),
+        };
+
+        let columns = [
+            ("null_count", stats.null_count),
+            ("distinct_count", stats.distinct_count),
+            ("min", stats.min_value),
+            ("max", stats.max_value),
+        ]
+        .into_iter()
+        .map(|(name, array)| Series::try_from((name, array)))
+        .collect::<PolarsResult<Vec<_>>>()
+        .and_then(DataFrame::new);
+
+        match columns {
+            Ok(df) => *out = make_dataframe(df),
+            Err(err) => return make_error(err),
+        }
+
+        std::ptr::null()
+    })
+}
+
+/// A parquet writer appending DataFrames with the same schema to a single file.
+pub struct polars_parquet_batched_writer_t {
+    /// `None` once the footer has been written.
+    inner: Option<polars::io::parquet::BatchedWriter<UserIOCallback>>,
+    schema: Schema,
+    row_group_size: Option<usize>,
+}
+
+/// Opens a parquet writer writing to `callback`, the file schema is given by `schema` which
+/// describes a struct whose fields are the columns as returned by `polars_dataframe_schema`.
+#[no_mangle]
+pub unsafe extern "C" fn polars_parquet_batched_writer_open(
+    schema: *const ArrowSchema,
+    options: *const polars_parquet_write_options_t,
+    user: *const c_void,
+    callback: IOCallback,
+    out: *mut *mut polars_parquet_batched_writer_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!schema.is_null());
+        assert!(!options.is_null());
+
+        let field = match ffi::import_field_from_c(&*schema) {
+            Ok(field) => field,
+            Err(err) => return make_error(err),
+        };
+        let arrow::datatypes::DataType::Struct(fields) = field.data_type else {
+            return make_error(polars_err!(
+                SchemaMismatch: "expected a struct schema whose fields are the columns"
+            ));
+        };
+        let schema: Schema = fields.iter().collect();
+
+        let options = match (*options).to_write_options() {
+            Ok(options) => options,
+            Err(err) => return make_error(err),
+        };
+
+        let w = UserIOCallback(callback, user);
+        let writer = match make_parquet_writer(w, &options).batched(&schema) {
+            Ok(writer) => writer,
+            Err(err) => return make_error(err),
+        };
+
+        *out = Box::into_raw(Box::new(polars_parquet_batched_writer_t {
+            inner: Some(writer),
+            schema,
+            row_group_size: options.row_group_size,
+        }));
+
+        std::ptr::null()
+    })
+}
+
+/// Writes the rows of `df` to the file, `df` must have the schema the writer was opened with.
+#[no_mangle]
+pub unsafe extern "C" fn polars_parquet_batched_writer_write_batch(
+    writer: *mut polars_parquet_batched_writer_t,
+    df: *mut polars_dataframe_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!writer.is_null());
+        assert!(!df.is_null());
+        let writer = &mut *writer;
+
+        let Some(inner) = writer.inner.as_mut() else {
+            return make_error(polars_err!(InvalidOperation: "parquet writer is already finished"));
+        };
+
+        // Schema equality ignores the order of the columns, which must match the file.
+        let schema = (*df).inner.schema();
+        if !schema.iter().eq(writer.schema.iter()) {
+            return make_error(polars_err!(
+                SchemaMismatch: "batch schema {:?} does not match the file schema {:?}",
+                schema, writer.schema
+            ));
+        }
+
+        let mut df = (*df).inner.clone();
+        df.align_chunks();
+
+        let result = match writer.row_group_size {
+            Some(size) if size > 0 && df.height() > size => (0..df.height())
+                .step_by(size)
+                .try_for_each(|offset| inner.write_batch(&df.slice(offset as i64, size))),
+            _ => inner.write_batch(&df),
+        };
+        if let Err(err) = result {
+            return make_error(err);
+        }
+
+        std::ptr::null()
+    })
+}
+
+/// Writes the footer of the file, no batch can be written afterwards. The writer must still be
+/// destroyed with `polars_parquet_batched_writer_destroy`.
+#[no_mangle]
+pub unsafe extern "C" fn polars_parquet_batched_writer_finish(
+    writer: *mut polars_parquet_batched_writer_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!writer.is_null());
+        let Some(mut inner) = (*writer).inner.take() else {
+            return make_error(polars_err!(InvalidOperation: "parquet writer is already finished"));
+        };
+
+        if let Err(err) = inner.finish() {
+            return make_error(err);
+        }
+
+        std::ptr::null()
+    })
+}
+
+/// Destroys the writer, the footer is not written if `polars_parquet_batched_writer_finish` was
+/// not called.
+#[no_mangle]
+pub unsafe extern "C" fn polars_parquet_batched_writer_destroy(
+    writer: *mut polars_parquet_batched_writer_t,
+) {
+    catch_panic(|| {
+        assert!(!writer.is_null());
+        let _ = Box::from_raw(writer);
+    })
+}
@@ -1,48 +1,170 @@
-use crate::{value::polars_value_type_t, *};
+use crate::{
+    value::{make_value, polars_value_type_t},
+    *,
+};
 
 pub(crate) fn make_series(series: Series) -> *mut polars_series_t {
     Box::into_raw(Box::new(polars_series_t { inner: series }))
 }
 
+/// Creates a series named `name` from an array according to the Arrow C Data interface.
+///
+/// # Safety
+/// The ownership of `array` is transferred to the series as in `polars_dataframe_new_from_carrow`,
+/// the caller should *not* release it.
+#[no_mangle]
+pub unsafe extern "C" fn polars_series_new_from_carrow(
+    name: *const u8,
+    namelen: usize,
+    schema: *const ArrowSchema,
+    array: ArrowArray,
+    out: *mut *mut polars_series_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!schema.is_null());
+        let name = std::slice::from_raw_parts(name, namelen);
+        let name = match std::str::from_utf8(name) {
+            Ok(name) => name,
+            Err(err) => return make_error(err),
+        };
+
+        let field = match ffi::import_field_from_c(&*schema) {
+            Ok(field) => field,
+            Err(err) => return make_error(err),
+        };
+
+        let array = match ffi::import_array_from_c(array, field.data_type) {
+            Ok(array) => array,
+            Err(err) => return make_error(err),
+        };
+
+        match Series::try_from((name, array)) {
+            Ok(series) => *out = make_series(series),
+            Err(err) => return make_error(err),
+        }
+
+        std::ptr::null()
+    })
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn polars_series_destroy(series: *mut polars_series_t) {
-    assert!(!series.is_null());
-    let _ = Box::from_raw(series);
+    catch_panic(|| {
+        assert!(!series.is_null());
+        let _ = Box::from_raw(series);
+    })
+}
+
+/// Returns a new handle sharing the data of `series`, each handle must be destroyed.
+#[no_mangle]
+pub unsafe extern "C" fn polars_series_clone(series: *mut polars_series_t) -> *mut polars_series_t {
+    catch_panic(|| {
+        assert!(!series.is_null());
+        make_series((*series).inner.clone())
+    })
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_series_type(series: *mut polars_series_t) -> polars_value_type_t {
-    polars_value_type_t::from_dtype((*series).inner.dtype())
+    catch_panic(|| polars_value_type_t::from_dtype((*series).inner.dtype()))
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_series_length(series: *mut polars_series_t) -> usize {
-    assert!(!series.is_null());
-    (*series).inner.len()
+    catch_panic(|| {
+        assert!(!series.is_null());
+        (*series).inner.len()
+    })
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_series_null_count(series: *mut polars_series_t) -> usize {
-    assert!(!series.is_null());
-    (*series).inner.null_count()
+    catch_panic(|| {
+        assert!(!series.is_null());
+        (*series).inner.null_count()
+    })
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_series_schema(series: *mut polars_series_t) -> ArrowSchema {
-    assert!(!series.is_null());
-    ffi::export_field_to_c(&(*series).inner.field().to_arrow())
+    catch_panic(|| {
+        assert!(!series.is_null());
+        ffi::export_field_to_c(&(*series).inner.field().to_arrow())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_series_num_chunks(series: *mut polars_series_t) -> usize {
+    catch_panic(|| {
+        assert!(!series.is_null());
+        (*series).inner.n_chunks()
+    })
+}
+
+/// Exports the chunk at `index` to `out` according to the Arrow C Data interface, its type
+/// matches the field returned by `polars_series_schema`. The buffers are shared with the series
+/// and kept alive until the array is released by the caller.
+#[no_mangle]
+pub unsafe extern "C" fn polars_series_export_carrow(
+    series: *mut polars_series_t,
+    index: usize,
+    out: *mut ArrowArray,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!series.is_null());
+        assert!(!out.is_null());
+        let series = &(*series).inner;
+        if index >= series.n_chunks() {
+            return make_error_with_kind(
+                polars_error_kind_t::PolarsErrorKindOutOfBounds,
+                format!(
+                    "chunk index {} is out of bounds for a series of {} chunks",
+                    index,
+                    series.n_chunks()
+                ),
+            );
+        }
+        *out = ffi::export_array_to_c(series.to_arrow(index));
+        std::ptr::null()
+    })
 }
 
 /// Returns whether or not the value at index `index` is null, return false if the index is out of
-/// bounds.
+/// bounds. Prefer `polars_series_is_null_checked` which reports it.
 #[no_mangle]
 pub unsafe extern "C" fn polars_series_is_null(series: *mut polars_series_t, index: usize) -> bool {
-    assert!(!series.is_null());
-    match (*series).inner.get(index) {
-        Ok(AnyValue::Null) => true,
-        Ok(_) => false,
-        Err(_) => false,
-    }
+    catch_panic(|| {
+        assert!(!series.is_null());
+        match (*series).inner.get(index) {
+            Ok(AnyValue::Null) => true,
+            Ok(_) => false,
+            Err(_) => false,
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_series_is_null_checked(
+    series: *mut polars_series_t,
+    index: usize,
+    out: *mut bool,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!series.is_null());
+        let series = &(*series).inner;
+        if index >= series.len() {
+            return make_error_with_kind(
+                polars_error_kind_t::PolarsErrorKindOutOfBounds,
+                format!(
+                    "index {} is out of bounds for a series of length {}",
+                    index,
+                    series.len()
+                ),
+            );
+        }
+        *out = series.is_null().get(index) == Some(true);
+        std::ptr::null()
+    })
 }
 
 #[no_mangle]
@@ -50,20 +172,54 @@ pub unsafe extern "C" fn polars_series_name(
     series: *mut polars_series_t,
     out: *mut *const u8,
 ) -> usize {
-    assert!(!series.is_null());
-    let name = (*series).inner.name();
-    *out = name.as_ptr();
-    name.len()
+    catch_panic(|| {
+        assert!(!series.is_null());
+        let name = (*series).inner.name();
+        *out = name.as_ptr();
+        name.len()
+    })
 }
 
+/// Returns the value at `index` or null on error, prefer `polars_series_get_checked` which
+/// reports it.
 #[no_mangle]
-pub unsafe extern "C" fn polars_series_get<'a>(
+pub unsafe extern "C" fn polars_series_get(
     series: *mut polars_series_t,
     index: usize,
-) -> *const polars_value_t<'a> {
-    assert!(!series.is_null());
-    let value = (*series).inner.get(index).unwrap();
-    Box::into_raw(Box::new(polars_value_t { inner: value }))
+) -> *const polars_value_t {
+    let mut out = std::ptr::null_mut();
+    let err = polars_series_get_checked(series, index, &mut out);
+    if !err.is_null() {
+        polars_error_destroy(err);
+    }
+    out
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn polars_series_get_checked(
+    series: *mut polars_series_t,
+    index: usize,
+    out: *mut *mut polars_value_t,
+) -> *const polars_error_t {
+    catch_panic(|| {
+        assert!(!series.is_null());
+        let series = &(*series).inner;
+        if index >= series.len() {
+            return make_error_with_kind(
+                polars_error_kind_t::PolarsErrorKindOutOfBounds,
+                format!(
+                    "index {} is out of bounds for a series of length {}",
+                    index,
+                    series.len()
+                ),
+            );
+        }
+        match series.get(index).and_then(make_value) {
+            Ok(value) => *out = value,
+            Err(err) => return make_error(err),
+        }
+        std::ptr::null()
+    })
 }
 
 macro_rules! gen_series_get {
@@ -74,15 +230,17 @@ macro_rules! gen_series_get {
             index: usize,
             out: *mut $t,
         ) -> *const polars_error_t {
-            assert!(!series.is_null());
-            match (*series).inner.get(index) {
-                Ok(AnyValue::$rt(value)) => {
-                    *out = value;
-                    std::ptr::null()
+            catch_panic(|| {
+                assert!(!series.is_null());
+                match (*series).inner.get(index) {
+                    Ok(AnyValue::$rt(value)) => {
+                        *out = value;
+                        std::ptr::null()
+                    }
+                    Ok(_) => make_error(polars_err!(SchemaMismatch: "series type is invalid")),
+                    Err(err) => make_error(err),
                 }
-                Ok(_) => make_error("series type is invalid"),
-                Err(err) => make_error(err),
-            }
+            })
         }
     };
 }
@@ -0,0 +1,346 @@
+//! Validation of arrays imported through the Arrow C Data interface, which trusts the buffers
+//! provided by the host.
+//!
+//! The layout of the array is checked before it is imported, since the import reads the buffers
+//! at the lengths declared by the array and its offsets. The C Data interface does not declare
+//! the size of the buffers, so a buffer shorter than the declared length of its array cannot be
+//! detected. The UTF-8 checks run on the imported arrays.
+
+use polars_core::utils::arrow::{
+    array::{Array, DictionaryArray, ListArray, MapArray, Utf8Array},
+    datatypes::{DataType as ArrowDataType, IntegerType, PhysicalType},
+    offset::Offset,
+    types::NativeType,
+};
+
+use crate::*;
+
+/// The checks run on imported Arrow arrays, use `polars_carrow_validation_default` to get the
+/// default checks. The lengths and offsets of the arrays must not be negative whatever the
+/// checks.
+#[repr(C)]
+pub struct polars_carrow_validation_t {
+    /// Checks that offsets are increasing and that the offsets of lists and maps are within the
+    /// length of their values.
+    offsets: bool,
+    /// Checks that string values are valid UTF-8, requires valid offsets.
+    utf8: bool,
+    /// Checks that the numbers of buffers and children match the data type and that the children
+    /// of structs and fixed size lists are at least as long as their parent.
+    lengths: bool,
+    /// Checks that the keys of dictionaries are within the length of their values.
+    keys: bool,
+}
+
+#[no_mangle]
+pub extern "C" fn polars_carrow_validation_default() -> polars_carrow_validation_t {
+    polars_carrow_validation_t {
+        offsets: true,
+        utf8: true,
+        lengths: true,
+        keys: true,
+    }
+}
+
+/// The layout of `struct ArrowArray` in `include/arrow.h`, the fields of `ArrowArray` are not
+/// public.
+#[repr(C)]
+struct RawArrowArray {
+    length: i64,
+    null_count: i64,
+    offset: i64,
+    n_buffers: i64,
+    n_children: i64,
+    buffers: *const *const c_void,
+    children: *const *const RawArrowArray,
+    dictionary: *const RawArrowArray,
+    release: Option<unsafe extern "C" fn(*mut RawArrowArray)>,
+    private_data: *mut c_void,
+}
+
+/// Runs the checks of `options` on the layout of `array` and its children before it is
+/// imported as an array of `data_type`.
+///
+/// # Safety
+/// The buffers of `array` must be at least as long as the lengths it declares.
+pub(crate) unsafe fn validate_carray(
+    array: &ArrowArray,
+    data_type: &ArrowDataType,
+    options: &polars_carrow_validation_t,
+) -> PolarsResult<()> {
+    let array = &*(array as *const ArrowArray).cast::<RawArrowArray>();
+    polars_ensure!(array.release.is_some(), ComputeError: "the array was released");
+    validate_raw(array, data_type, options)
+}
+
+unsafe fn validate_raw(
+    array: &RawArrowArray,
+    data_type: &ArrowDataType,
+    options: &polars_carrow_validation_t,
+) -> PolarsResult<()> {
+    let offset = to_usize(array.offset, "offset")?;
+    let length = to_usize(array.length, "length")?;
+    // The layout of unions depends on their mode, they are left to the import.
+    let Some((n_buffers, children)) = layout(data_type) else {
+        return Ok(());
+    };
+
+    if options.lengths {
+        polars_ensure!(
+            n_buffers.contains(&array.n_buffers),
+            ComputeError: "array of type {:?} has {} buffers, expected {:?}",
+            data_type, array.n_buffers, n_buffers
+        );
+        polars_ensure!(
+            array.n_children == children.len() as i64,
+            ComputeError: "array of type {:?} has {} children, expected {}",
+            data_type, array.n_children, children.len()
+        );
+    }
+
+    let check_offsets = options.offsets || options.utf8;
+    use ArrowDataType::*;
+    match data_type.to_logical_type() {
+        Utf8 | Binary if check_offsets => validate_offsets::<i32>(array, offset, length, None)?,
+        LargeUtf8 | LargeBinary if check_offsets => {
+            validate_offsets::<i64>(array, offset, length, None)?
+        }
+        List(_) | Map(..) if check_offsets => {
+            let values_len = to_usize(child(array, 0)?.length, "length")?;
+            validate_offsets::<i32>(array, offset, length, Some(values_len))?
+        }
+        LargeList(_) if check_offsets => {
+            let values_len = to_usize(child(array, 0)?.length, "length")?;
+            validate_offsets::<i64>(array, offset, length, Some(values_len))?
+        }
+        FixedSizeList(_, size) if options.lengths => {
+            let values_len = to_usize(child(array, 0)?.length, "length")?;
+            let Some(expected) = offset
+                .checked_add(length)
+                .and_then(|n| n.checked_mul(*size))
+            else {
+                polars_bail!(
+                    ComputeError: "fixed size list of offset {} and length {} with lists of size {} overflows",
+                    offset, length, size
+                );
+            };
+            polars_ensure!(
+                values_len >= expected,
+                ComputeError: "fixed size list values of length {} for {} lists of size {}",
+                values_len, offset + length, size
+            );
+        }
+        Struct(_) if options.lengths => {
+            for i in 0..children.len() {
+                let field = child(array, i)?;
+                polars_ensure!(
+                    field.length as usize >= offset + length,
+                    ComputeError: "struct field of length {} in a struct of length {}",
+                    field.length, offset + length
+                );
+            }
+        }
+        Dictionary(key_type, values_type, _) => {
+            polars_ensure!(
+                !array.dictionary.is_null(),
+                ComputeError: "dictionary array without values"
+            );
+            let values = &*array.dictionary;
+            if options.keys {
+                let values_len = to_usize(values.length, "length")?;
+                use IntegerType::*;
+                match key_type {
+                    Int8 => validate_keys::<i8>(array, offset, length, values_len)?,
+                    Int16 => validate_keys::<i16>(array, offset, length, values_len)?,
+                    Int32 => validate_keys::<i32>(array, offset, length, values_len)?,
+                    Int64 => validate_keys::<i64>(array, offset, length, values_len)?,
+                    UInt8 => validate_keys::<u8>(array, offset, length, values_len)?,
+                    UInt16 => validate_keys::<u16>(array, offset, length, values_len)?,
+                    UInt32 => validate_keys::<u32>(array, offset, length, values_len)?,
+                    UInt64 => validate_keys::<u64>(array, offset, length, values_len)?,
+                }
+            }
+            validate_raw(values, values_type, options)?;
+        }
+        _ => {}
+    }
+
+    for (i, child_type) in children.into_iter().enumerate() {
+        validate_raw(child(array, i)?, child_type, options)?;
+    }
+    Ok(())
+}
+
+/// Returns the numbers of buffers and the data types of the children of an array of
+/// `data_type`, or `None` for unions.
+fn layout(
+    data_type: &ArrowDataType,
+) -> Option<(std::ops::RangeInclusive<i64>, Vec<&ArrowDataType>)> {
+    use ArrowDataType::*;
+    Some(match data_type.to_logical_type() {
+        // Older implementations, arrow2 included, export a null validity buffer.
+        Null => (0..=1, vec![]),
+        Binary | LargeBinary | Utf8 | LargeUtf8 => (3..=3, vec![]),
+        List(field) | LargeList(field) | Map(field, _) => (2..=2, vec![&field.data_type]),
+        FixedSizeList(field, _) => (1..=1, vec![&field.data_type]),
+        Struct(fields) => (1..=1, fields.iter().map(|field| &field.data_type).collect()),
+        Union(..) => return None,
+        // The validity and the values, or the keys of dictionaries.
+        _ => (2..=2, vec![]),
+    })
+}
+
+fn to_usize(value: i64, name: &str) -> PolarsResult<usize> {
+    usize::try_from(value)
+        .map_err(|_| polars_err!(ComputeError: "array {} {} is negative", name, value))
+}
+
+unsafe fn buffer(array: &RawArrowArray, index: usize) -> PolarsResult<*const c_void> {
+    polars_ensure!(
+        !array.buffers.is_null() && (index as i64) < array.n_buffers,
+        ComputeError: "array without buffer {}", index
+    );
+    let ptr = *array.buffers.add(index);
+    polars_ensure!(!ptr.is_null(), ComputeError: "array with a null buffer {}", index);
+    Ok(ptr)
+}
+
+unsafe fn child(array: &RawArrowArray, index: usize) -> PolarsResult<&RawArrowArray> {
+    polars_ensure!(
+        !array.children.is_null() && (index as i64) < array.n_children,
+        ComputeError: "array without child {}", index
+    );
+    let ptr = *array.children.add(index);
+    polars_ensure!(!ptr.is_null(), ComputeError: "array with a null child {}", index);
+    Ok(&*ptr)
+}
+
+/// Checks the offsets of the slots of `array`, the last one must be at most `values_len` when
+/// the length of the values is known.
+unsafe fn validate_offsets<O: Offset>(
+    array: &RawArrowArray,
+    offset: usize,
+    length: usize,
+    values_len: Option<usize>,
+) -> PolarsResult<()> {
+    if length == 0 {
+        return Ok(());
+    }
+
+    // The import copies misaligned buffers, so they are read unaligned here.
+    let offsets = buffer(array, 1)?.cast::<O>();
+    let mut previous = offsets.add(offset).read_unaligned();
+    polars_ensure!(previous >= O::zero(), ComputeError: "offsets must be non-negative");
+    for i in offset + 1..=offset + length {
+        let current = offsets.add(i).read_unaligned();
+        polars_ensure!(previous <= current, ComputeError: "offsets must be increasing");
+        previous = current;
+    }
+
+    if let Some(values_len) = values_len {
+        polars_ensure!(
+            previous.to_usize() <= values_len,
+            ComputeError: "offset {} is out of bounds for values of length {}",
+            previous.to_usize(), values_len
+        );
+    }
+    Ok(())
+}
+
+/// Checks that the valid keys of `array` index the values of the dictionary.
+unsafe fn validate_keys<K: NativeType + TryInto<usize>>(
+    array: &RawArrowArray,
+    offset: usize,
+    length: usize,
+    values_len: usize,
+) -> PolarsResult<()> {
+    if length == 0 {
+        return Ok(());
+    }
+
+    // A null count of -1 means it was not computed, the validity buffer may then be omitted.
+    let validity = match array.null_count {
+        0 => None,
+        n if n > 0 => Some(buffer(array, 0)?.cast::<u8>()),
+        _ if array.buffers.is_null() || array.n_buffers < 1 => None,
+        _ => Some((*array.buffers).cast::<u8>()).filter(|validity| !validity.is_null()),
+    };
+    let keys = buffer(array, 1)?.cast::<K>();
+    for i in offset..offset + length {
+        if let Some(validity) = validity {
+            if *validity.add(i / 8) & (1 << (i % 8)) == 0 {
+                continue;
+            }
+        }
+
+        let key = keys.add(i).read_unaligned();
+        match key.try_into() {
+            Ok(key) if key < values_len => {}
+            _ => polars_bail!(
+                ComputeError: "dictionary key {:?} is out of bounds for values of length {}",
+                key, values_len
+            ),
+        }
+    }
+    Ok(())
+}
+
+/// Runs the UTF-8 check of `options` on the imported `array` and its children, the layout must
+/// have been checked by `validate_carray`.
+pub(crate) fn validate_array(
+    array: &dyn Array,
+    options: &polars_carrow_validation_t,
+) -> PolarsResult<()> {
+    if !options.utf8 {
+        return Ok(());
+    }
+
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Utf8 => validate_utf8(downcast::<Utf8Array<i32>>(array)),
+        LargeUtf8 => validate_utf8(downcast::<Utf8Array<i64>>(array)),
+        List => validate_array(downcast::<ListArray<i32>>(array).values().as_ref(), options),
+        LargeList => validate_array(downcast::<ListArray<i64>>(array).values().as_ref(), options),
+        Map => validate_array(downcast::<MapArray>(array).field().as_ref(), options),
+        FixedSizeList => {
+            let array = downcast::<arrow::array::FixedSizeListArray>(array);
+            validate_array(array.values().as_ref(), options)
+        }
+        Struct => {
+            for field in downcast::<StructArray>(array).values() {
+                validate_array(field.as_ref(), options)?;
+            }
+            Ok(())
+        }
+        Dictionary(key_type) => {
+            use IntegerType::*;
+            let values = match key_type {
+                Int8 => downcast::<DictionaryArray<i8>>(array).values(),
+                Int16 => downcast::<DictionaryArray<i16>>(array).values(),
+                Int32 => downcast::<DictionaryArray<i32>>(array).values(),
+                Int64 => downcast::<DictionaryArray<i64>>(array).values(),
+                UInt8 => downcast::<DictionaryArray<u8>>(array).values(),
+                UInt16 => downcast::<DictionaryArray<u16>>(array).values(),
+                UInt32 => downcast::<DictionaryArray<u32>>(array).values(),
+                UInt64 => downcast::<DictionaryArray<u64>>(array).values(),
+            };
+            validate_array(values.as_ref(), options)
+        }
+        _ => Ok(()),
+    }
+}
+
+fn downcast<A: 'static>(array: &dyn Array) -> &A {
+    array.as_any().downcast_ref().unwrap()
+}
+
+fn validate_utf8<O: Offset>(array: &Utf8Array<O>) -> PolarsResult<()> {
+    let values = array.values();
+    for (i, w) in array.offsets().buffer().windows(2).enumerate() {
+        let value = &values[w[0].to_usize()..w[1].to_usize()];
+        if let Err(err) = std::str::from_utf8(value) {
+            polars_bail!(ComputeError: "invalid UTF-8 in value {}: {}", i, err);
+        }
+    }
+    Ok(())
+}
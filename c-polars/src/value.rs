@@ -1,7 +1,46 @@
-use polars_core::utils::arrow::array::Int64Array;
-
 use crate::{series::make_series, *};
 
+/// Copies the borrowed data of `value` so that it can outlive its series, the time zone of a
+/// datetime is kept by the returned value.
+pub(crate) fn make_value(value: AnyValue<'_>) -> PolarsResult<*mut polars_value_t> {
+    let time_zone = match &value {
+        AnyValue::Datetime(_, _, time_zone) => (*time_zone).clone(),
+        _ => None,
+    };
+    make_value_in_time_zone(value, time_zone)
+}
+
+fn make_value_in_time_zone(
+    value: AnyValue<'_>,
+    time_zone: Option<TimeZone>,
+) -> PolarsResult<*mut polars_value_t> {
+    Ok(Box::into_raw(Box::new(polars_value_t {
+        inner: to_static(value)?,
+        time_zone,
+    })))
+}
+
+/// Converts `value` to a value owning its data, `AnyValue::into_static` fails for the values
+/// borrowing their time zone or their categories. Time zones are dropped, the field types of
+/// structs keep them, and categories are resolved to strings.
+fn to_static(value: AnyValue<'_>) -> PolarsResult<AnyValue<'static>> {
+    Ok(match value {
+        AnyValue::Datetime(v, tu, _) => AnyValue::Datetime(v, tu, &None),
+        AnyValue::Duration(v, tu) => AnyValue::Duration(v, tu),
+        AnyValue::Categorical(..) => {
+            AnyValue::Utf8Owned(value.get_str().unwrap_or_default().into())
+        }
+        AnyValue::Struct(_, _, fields) => {
+            let values = value
+                ._iter_struct_av()
+                .map(to_static)
+                .collect::<PolarsResult<Vec<_>>>()?;
+            AnyValue::StructOwned(Box::new((values, fields.to_vec())))
+        }
+        value => value.into_static()?,
+    })
+}
+
 #[repr(C)]
 pub enum polars_value_type_t {
     PolarsValueTypeNull,
@@ -73,13 +112,15 @@ impl polars_value_type_t {
 
 #[no_mangle]
 pub extern "C" fn polars_value_type(value: *mut polars_value_t) -> polars_value_type_t {
-    polars_value_type_t::from_dtype(unsafe { &(*value).inner.dtype() })
+    catch_panic(|| polars_value_type_t::from_dtype(unsafe { &(*value).inner.dtype() }))
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn polars_value_destroy(value: *mut polars_value_t) {
-    assert!(!value.is_null());
-    let _ = Box::from_raw(value);
+    catch_panic(|| {
+        assert!(!value.is_null());
+        let _ = Box::from_raw(value);
+    })
 }
 
 macro_rules! gen_value_get {
@@ -89,11 +130,17 @@ macro_rules! gen_value_get {
             value: *mut polars_value_t,
             out: *mut $t,
         ) -> *const polars_error_t {
-            match (*value).inner {
-                AnyValue::$rt(value) => *out = value,
-                _ => return make_error(concat!("value is not of type ", stringify!($rt))),
-            }
-            std::ptr::null()
+            catch_panic(|| {
+                match (*value).inner {
+                    AnyValue::$rt(value) => *out = value,
+                    _ => {
+                        return make_error(polars_err!(
+                            SchemaMismatch: concat!("value is not of type ", stringify!($rt))
+                        ))
+                    }
+                }
+                std::ptr::null()
+            })
         }
     };
 }
@@ -116,11 +163,13 @@ pub unsafe extern "C" fn polars_value_list_get(
     value: *mut polars_value_t,
     out: *mut *mut polars_series_t,
 ) -> *const polars_error_t {
-    match &(*value).inner {
-        AnyValue::List(series) => *out = make_series(series.clone()),
-        _ => return make_error("value is not of type list"),
-    }
-    std::ptr::null()
+    catch_panic(|| {
+        match &(*value).inner {
+            AnyValue::List(series) => *out = make_series(series.clone()),
+            _ => return make_error(polars_err!(SchemaMismatch: "value is not of type list")),
+        }
+        std::ptr::null()
+    })
 }
 
 #[no_mangle]
@@ -129,14 +178,16 @@ pub unsafe extern "C" fn polars_value_utf8_get(
     user: *mut c_void,
     callback: IOCallback,
 ) -> *const polars_error_t {
-    let mut w = UserIOCallback(callback, user);
-    let Err(err) = (match (*value).inner {
-        AnyValue::Utf8(s) => w.write(s.as_bytes()),
-        _ => return make_error("value is not of type utf8"),
-    }) else {
-        return std::ptr::null();
-    };
-    make_error(err)
+    catch_panic(|| {
+        let mut w = UserIOCallback(callback, user);
+        let Err(err) = (match (*value).inner.get_str() {
+            Some(s) => w.write(s.as_bytes()),
+            None => return make_error(polars_err!(SchemaMismatch: "value is not of type utf8")),
+        }) else {
+            return std::ptr::null();
+        };
+        make_error(err)
+    })
 }
 
 #[no_mangle]
@@ -145,50 +196,72 @@ pub unsafe extern "C" fn polars_value_binary_get(
     user: *mut c_void,
     callback: IOCallback,
 ) -> *const polars_error_t {
-    let mut w = UserIOCallback(callback, user);
-    let Err(err) = (match (*value).inner {
-        AnyValue::Binary(s) => w.write(s),
-        _ => return make_error("value is not of type utf8"),
-    }) else {
-        return std::ptr::null();
-    };
-    make_error(err)
+    catch_panic(|| {
+        let mut w = UserIOCallback(callback, user);
+        let Err(err) = (match &(*value).inner {
+            AnyValue::Binary(s) => w.write(s),
+            AnyValue::BinaryOwned(s) => w.write(s),
+            _ => return make_error(polars_err!(SchemaMismatch: "value is not of type binary")),
+        }) else {
+            return std::ptr::null();
+        };
+        make_error(err)
+    })
 }
 
 /// Used to get value of of a Struct value fields.
-///
-/// NOTE: The value producing the new value must outlive the value from the field.
-///
-/// Safety: Values lifetimes must be valid and only support physical dtypes for now.
 #[no_mangle]
-pub unsafe extern "C" fn polars_value_struct_get<'a: 'b, 'b>(
-    value: *mut polars_value_t<'a>,
+pub unsafe extern "C" fn polars_value_struct_get(
+    value: *mut polars_value_t,
     fieldidx: usize,
-    out: *mut *mut polars_value_t<'b>,
+    out: *mut *mut polars_value_t,
 ) -> *const polars_error_t {
-    let AnyValue::Struct(value_index, sarray, fields) = (*value).inner else {
-        return make_error("invalid type for value");
-    };
+    catch_panic(|| {
+        let AnyValue::StructOwned(payload) = &(*value).inner else {
+            return make_error(polars_err!(SchemaMismatch: "invalid type for value"));
+        };
 
-    let Some(series) = sarray.values().get(fieldidx) else {
-        return make_error(format!("invalid field index {fieldidx}"));
-    };
+        let Some(value) = payload.0.get(fieldidx) else {
+            return make_error_with_kind(
+                polars_error_kind_t::PolarsErrorKindOutOfBounds,
+                format!("invalid field index {fieldidx}"),
+            );
+        };
 
-    let field = &fields[fieldidx];
-
-    let value = match field.data_type() {
-        DataType::Int64 => {
-            let array = series.as_any().downcast_ref::<Int64Array>().unwrap();
-            array.get(value_index).map(|val| AnyValue::Int64(val))
+        // The values of the fields do not hold their time zone, it is kept by the field type.
+        let time_zone = match payload.1.get(fieldidx).map(|field| field.data_type()) {
+            Some(DataType::Datetime(_, time_zone)) => time_zone.clone(),
+            _ => None,
+        };
+        match make_value_in_time_zone(value.clone(), time_zone) {
+            Ok(value) => *out = value,
+            Err(err) => return make_error(err),
         }
-        _ => unimplemented!("{:?}", field.data_type()),
-    };
-
-    let value = value.unwrap_or(AnyValue::Null);
 
-    *out = Box::into_raw(Box::new(polars_value_t { inner: value }));
+        std::ptr::null()
+    })
+}
 
-    std::ptr::null()
+/// Returns the time zone of a datetime value, the time zone is set to null with a length of 0 when
+/// the value has none. The returned string is owned by the value.
+#[no_mangle]
+pub unsafe extern "C" fn polars_value_time_zone(
+    value: *mut polars_value_t,
+    out: *mut *const u8,
+) -> usize {
+    catch_panic(|| {
+        assert!(!value.is_null());
+        match &(*value).time_zone {
+            Some(time_zone) => {
+                *out = time_zone.as_ptr();
+                time_zone.len()
+            }
+            None => {
+                *out = std::ptr::null();
+                0
+            }
+        }
+    })
 }
 
 /// Returns the element type of the provided value which must be a list.
@@ -197,8 +270,8 @@ pub unsafe extern "C" fn polars_value_struct_get<'a: 'b, 'b>(
 /// and unkown.
 #[no_mangle]
 pub unsafe extern "C" fn polars_value_list_type(value: *mut polars_value_t) -> polars_value_type_t {
-    match (*value).inner.dtype() {
+    catch_panic(|| match (*value).inner.dtype() {
         DataType::List(eltype) => polars_value_type_t::from_dtype(&eltype),
         _ => polars_value_type_t::PolarsValueTypeUnknown,
-    }
+    })
 }
@@ -36,6 +36,79 @@ struct ArrowArray
     private_data::Ptr{Cvoid}
 end
 
+struct ArrowArrayStream
+    get_schema::Ptr{Cvoid}
+    get_next::Ptr{Cvoid}
+    get_last_error::Ptr{Cvoid}
+    release::Ptr{Cvoid}
+    private_data::Ptr{Cvoid}
+end
+
+"""
+| Enumerator | Note |
+| :--------- | :--- |
+| PolarsCsvEncodingLossyUtf8 | Invalid utf8 bytes are replaced with the replacement character. |
+"""
+@cenum polars_csv_encoding_t::UInt32 begin
+    PolarsCsvEncodingUtf8 = 0
+    PolarsCsvEncodingLossyUtf8 = 1
+end
+
+"""
+The kind of a `polars_error_t`, it mirrors the variants of `PolarsError`.
+
+| Enumerator | Note |
+| :--------- | :--- |
+| PolarsErrorKindPanic | A panic was caught at the boundary of the C API. |
+"""
+@cenum polars_error_kind_t::UInt32 begin
+    PolarsErrorKindColumnNotFound = 0
+    PolarsErrorKindSchemaMismatch = 1
+    PolarsErrorKindComputeError = 2
+    PolarsErrorKindInvalidOperation = 3
+    PolarsErrorKindIo = 4
+    PolarsErrorKindShapeMismatch = 5
+    PolarsErrorKindOutOfBounds = 6
+    PolarsErrorKindNoData = 7
+    PolarsErrorKindDuplicate = 8
+    PolarsErrorKindUtf8 = 9
+    PolarsErrorKindPanic = 10
+end
+
+@cenum polars_ipc_compression_t::UInt32 begin
+    PolarsIpcCompressionUncompressed = 0
+    PolarsIpcCompressionLZ4 = 1
+    PolarsIpcCompressionZSTD = 2
+end
+
+@cenum polars_parallel_strategy_t::UInt32 begin
+    PolarsParallelStrategyAuto = 0
+    PolarsParallelStrategyNone = 1
+    PolarsParallelStrategyColumns = 2
+    PolarsParallelStrategyRowGroups = 3
+end
+
+@cenum polars_parquet_compression_t::UInt32 begin
+    PolarsParquetCompressionUncompressed = 0
+    PolarsParquetCompressionSnappy = 1
+    PolarsParquetCompressionGzip = 2
+    PolarsParquetCompressionLzo = 3
+    PolarsParquetCompressionBrotli = 4
+    PolarsParquetCompressionZstd = 5
+    PolarsParquetCompressionLz4Raw = 6
+end
+
+"""
+| Enumerator | Note |
+| :--------- | :--- |
+| PolarsQuoteStyleNecessary | Quotes fields only when they contain a quote, the separator or a line terminator. |
+"""
+@cenum polars_quote_style_t::UInt32 begin
+    PolarsQuoteStyleNecessary = 0
+    PolarsQuoteStyleAlways = 1
+    PolarsQuoteStyleNonNumeric = 2
+end
+
 @cenum polars_value_type_t::UInt32 begin
     PolarsValueTypeNull = 0
     PolarsValueTypeBoolean = 1
@@ -56,6 +129,11 @@ end
     PolarsValueTypeUnknown = 16
 end
 
+"""
+A CSV reader returning the file as a sequence of DataFrames, see `polars_csv_batched_reader_next_batches`.
+"""
+mutable struct polars_csv_batched_reader_t end
+
 mutable struct polars_dataframe_t end
 
 mutable struct polars_error_t end
@@ -66,16 +144,260 @@ mutable struct polars_lazy_frame_t end
 
 mutable struct polars_lazy_group_by_t end
 
+"""
+A parquet writer appending DataFrames with the same schema to a single file.
+"""
+mutable struct polars_parquet_batched_writer_t end
+
+mutable struct polars_parquet_metadata_t end
+
 mutable struct polars_series_t end
 
+"""
+A value which owns its data, it stays valid after the series it was taken from is destroyed.
+"""
 mutable struct polars_value_t end
 
+"""
+The checks run on imported Arrow arrays, use `polars_carrow_validation_default` to get the default checks. The lengths and offsets of the arrays must not be negative whatever the checks.
+
+| Field | Note |
+| :---- | :--- |
+| offsets | Checks that offsets are increasing and that the offsets of lists and maps are within the length of their values. |
+| utf8 | Checks that string values are valid UTF-8, requires valid offsets. |
+| lengths | Checks that the numbers of buffers and children match the data type and that the children of structs and fixed size lists are at least as long as their parent. |
+| keys | Checks that the keys of dictionaries are within the length of their values. |
+"""
+struct polars_carrow_validation_t
+    offsets::Bool
+    utf8::Bool
+    lengths::Bool
+    keys::Bool
+end
+
 # typedef intptr_t ( * IOCallback ) ( const void * user , const uint8_t * data , uintptr_t len )
 """
 The callback provided for display functions, returns -1 on error.
 """
 const IOCallback = Ptr{Cvoid}
 
+"""
+Options used by `polars_dataframe_write_parquet_with_options`, use `polars_parquet_write_options_default` to get the default options.
+
+| Field | Note |
+| :---- | :--- |
+| compression_level | Compression level used by gzip, brotli and zstd, a negative level uses the codec default. |
+| statistics | Whether to compute and write column statistics. |
+| row_group_size | Number of rows per row group, `SIZE_MAX` uses the polars default. |
+| data_pagesize_limit | Size limit of data pages in bytes, `SIZE_MAX` uses the polars default. |
+"""
+struct polars_parquet_write_options_t
+    compression::polars_parquet_compression_t
+    compression_level::Int32
+    statistics::Bool
+    row_group_size::Csize_t
+    data_pagesize_limit::Csize_t
+end
+
+"""
+Options used by the parquet readers, use `polars_parquet_read_options_default` to get the default options.
+
+| Field | Note |
+| :---- | :--- |
+| columns | Names of the columns to read, all columns are read when both `n_columns` and `n_projection` are 0. |
+| projection | Indices of the columns to read, ignored when columns are selected by name. |
+| n_rows | Stop reading after this number of rows, `SIZE_MAX` reads all the rows. |
+| row_count_name | Name of the row count column, no row count column is added when empty. |
+| use_statistics | Whether to use the statistics to skip pages which cannot match. |
+| rechunk | Whether to aggregate the chunks into a single chunk once read. |
+"""
+struct polars_parquet_read_options_t
+    columns::Ptr{Ptr{UInt8}}
+    columns_lens::Ptr{Csize_t}
+    n_columns::Csize_t
+    projection::Ptr{Csize_t}
+    n_projection::Csize_t
+    n_rows::Csize_t
+    row_count_name::Ptr{UInt8}
+    row_count_name_len::Csize_t
+    row_count_offset::UInt32
+    parallel::polars_parallel_strategy_t
+    use_statistics::Bool
+    rechunk::Bool
+end
+
+# typedef intptr_t ( * ReadCallback ) ( const void * user , uint8_t * data , uintptr_t len )
+"""
+The callback used to read at most `len` bytes into `data`, returns the number of bytes read, 0 at the end of the stream and -1 on error.
+"""
+const ReadCallback = Ptr{Cvoid}
+
+# typedef int64_t ( * SeekCallback ) ( const void * user , int64_t offset , int32_t whence )
+"""
+The callback used to move the stream position, `whence` is 0 for an offset relative to the start of the stream, 1 for the current position and 2 for the end of the stream (as in `lseek`). Returns the new position from the start of the stream or -1 on error.
+"""
+const SeekCallback = Ptr{Cvoid}
+
+# typedef int64_t ( * TellCallback ) ( const void * user )
+"""
+The callback returning the current position from the start of the stream or -1 on error.
+"""
+const TellCallback = Ptr{Cvoid}
+
+"""
+A host provided stream which readers can use in place of a file path.
+"""
+struct polars_reader_callbacks_t
+    user::Ptr{Cvoid}
+    read::ReadCallback
+    seek::SeekCallback
+    tell::TellCallback
+end
+
+"""
+Options used by `polars_dataframe_read_ipc`, use `polars_ipc_read_options_default` to get the default options.
+
+| Field | Note |
+| :---- | :--- |
+| columns | Names of the columns to read, all columns are read when `n_columns` is 0. |
+| memory_map | Whether to memory-map the file instead of reading it into memory. |
+"""
+struct polars_ipc_read_options_t
+    columns::Ptr{Ptr{UInt8}}
+    columns_lens::Ptr{Csize_t}
+    n_columns::Csize_t
+    memory_map::Bool
+end
+
+"""
+Options used by `polars_dataframe_read_csv`, string arrays are given as an array of pointers along with an array of lengths. Use `polars_csv_read_options_default` to get the default options.
+
+| Field | Note |
+| :---- | :--- |
+| quote_char | The quote character, 0 disables quoting. |
+| comment_char | Lines starting with this character are skipped, 0 disables comments. |
+| null_values | Values which are interpreted as null in all columns. |
+| n_rows | Stop reading after this number of rows, `SIZE_MAX` reads all the rows. |
+| columns | Names of the columns to read, all columns are read when `n_columns` is 0. |
+| dtypes_names | Overrides the inferred dtypes of the named columns. |
+"""
+struct polars_csv_read_options_t
+    separator::UInt8
+    has_header::Bool
+    quote_char::UInt8
+    comment_char::UInt8
+    skip_rows::Csize_t
+    null_values::Ptr{Ptr{UInt8}}
+    null_values_lens::Ptr{Csize_t}
+    n_null_values::Csize_t
+    n_rows::Csize_t
+    columns::Ptr{Ptr{UInt8}}
+    columns_lens::Ptr{Csize_t}
+    n_columns::Csize_t
+    dtypes_names::Ptr{Ptr{UInt8}}
+    dtypes_names_lens::Ptr{Csize_t}
+    dtypes::Ptr{polars_value_type_t}
+    n_dtypes::Csize_t
+    try_parse_dates::Bool
+end
+
+"""
+Options used by `polars_dataframe_write_csv`, empty format strings use the polars defaults. Use `polars_csv_write_options_default` to get the default options.
+
+| Field | Note |
+| :---- | :--- |
+| float_precision | Number of decimals written for floats, `SIZE_MAX` writes all of them. |
+| null_value | Representation of null values, empty by default. |
+"""
+struct polars_csv_write_options_t
+    separator::UInt8
+    has_header::Bool
+    quote_char::UInt8
+    quote_style::polars_quote_style_t
+    date_format::Ptr{UInt8}
+    date_format_len::Csize_t
+    datetime_format::Ptr{UInt8}
+    datetime_format_len::Csize_t
+    time_format::Ptr{UInt8}
+    time_format_len::Csize_t
+    float_precision::Csize_t
+    null_value::Ptr{UInt8}
+    null_value_len::Csize_t
+end
+
+"""
+Options used by `polars_lazy_frame_scan_parquet`, use `polars_parquet_scan_options_default` to get the default options.
+
+| Field | Note |
+| :---- | :--- |
+| n_rows | Stop reading after this number of rows, `SIZE_MAX` reads all the rows. |
+| row_count_name | Name of the row count column, no row count column is added when empty. |
+| hive_partitioning | Whether to add the `key=value` directories of the scanned files below the non-glob prefix of their path as columns. |
+"""
+struct polars_parquet_scan_options_t
+    n_rows::Csize_t
+    row_count_name::Ptr{UInt8}
+    row_count_name_len::Csize_t
+    row_count_offset::UInt32
+    parallel::polars_parallel_strategy_t
+    low_memory::Bool
+    hive_partitioning::Bool
+end
+
+"""
+Options used by `polars_lazy_frame_scan_csv`, use `polars_csv_scan_options_default` to get the default options.
+
+| Field | Note |
+| :---- | :--- |
+| quote_char | The quote character, 0 disables quoting. |
+| comment_char | Lines starting with this character are skipped, 0 disables comments. |
+| n_rows | Stop reading after this number of rows, `SIZE_MAX` reads all the rows. |
+| infer_schema_length | Number of rows used to infer the schema, `SIZE_MAX` uses the whole file. |
+| dtypes_names | Overrides the inferred dtypes of the named columns. |
+| row_count_name | Name of the row count column, no row count column is added when empty. |
+| hive_partitioning | Whether to add the `key=value` directories of the scanned files below the non-glob prefix of their path as columns. |
+"""
+struct polars_csv_scan_options_t
+    separator::UInt8
+    has_header::Bool
+    quote_char::UInt8
+    comment_char::UInt8
+    skip_rows::Csize_t
+    n_rows::Csize_t
+    infer_schema_length::Csize_t
+    dtypes_names::Ptr{Ptr{UInt8}}
+    dtypes_names_lens::Ptr{Csize_t}
+    dtypes::Ptr{polars_value_type_t}
+    n_dtypes::Csize_t
+    encoding::polars_csv_encoding_t
+    ignore_errors::Bool
+    low_memory::Bool
+    row_count_name::Ptr{UInt8}
+    row_count_name_len::Csize_t
+    row_count_offset::UInt32
+    hive_partitioning::Bool
+end
+
+"""
+Options used by `polars_lazy_frame_scan_ndjson`, use `polars_ndjson_scan_options_default` to get the default options.
+
+| Field | Note |
+| :---- | :--- |
+| n_rows | Stop reading after this number of rows, `SIZE_MAX` reads all the rows. |
+| infer_schema_length | Number of rows used to infer the schema, `SIZE_MAX` uses the whole file. |
+| row_count_name | Name of the row count column, no row count column is added when empty. |
+| hive_partitioning | Whether to add the `key=value` directories of the scanned files below the non-glob prefix of their path as columns. |
+"""
+struct polars_ndjson_scan_options_t
+    n_rows::Csize_t
+    infer_schema_length::Csize_t
+    low_memory::Bool
+    row_count_name::Ptr{UInt8}
+    row_count_name_len::Csize_t
+    row_count_offset::UInt32
+    hive_partitioning::Bool
+end
+
 function polars_version(out)
     @ccall libpolars.polars_version(out::Ptr{Ptr{UInt8}})::Csize_t
 end
@@ -84,14 +406,36 @@ function polars_error_message(err, data)
     @ccall libpolars.polars_error_message(err::Ptr{polars_error_t}, data::Ptr{Ptr{UInt8}})::Csize_t
 end
 
+function polars_error_kind(err)
+    @ccall libpolars.polars_error_kind(err::Ptr{polars_error_t})::polars_error_kind_t
+end
+
 function polars_error_destroy(err)
     @ccall libpolars.polars_error_destroy(err::Ptr{polars_error_t})::Cvoid
 end
 
+"""
+    polars_dataframe_new()
+
+Creates an empty DataFrame, it must be destroyed with `polars_dataframe_destroy`.
+"""
+function polars_dataframe_new()
+    @ccall libpolars.polars_dataframe_new()::Ptr{polars_dataframe_t}
+end
+
+"""
+    polars_dataframe_size(df, rows, cols)
+
+Errors are ignored, prefer `polars_dataframe_size_checked` which reports them.
+"""
 function polars_dataframe_size(df, rows, cols)
     @ccall libpolars.polars_dataframe_size(df::Ptr{polars_dataframe_t}, rows::Ptr{Csize_t}, cols::Ptr{Csize_t})::Cvoid
 end
 
+function polars_dataframe_size_checked(df, rows, cols)
+    @ccall libpolars.polars_dataframe_size_checked(df::Ptr{polars_dataframe_t}, rows::Ptr{Csize_t}, cols::Ptr{Csize_t})::Ptr{polars_error_t}
+end
+
 """
     polars_dataframe_new_from_carrow(cfield, carray)
 
@@ -105,6 +449,26 @@ function polars_dataframe_new_from_carrow(cfield, carray)
     @ccall libpolars.polars_dataframe_new_from_carrow(cfield::Ptr{ArrowSchema}, carray::ArrowArray)::Ptr{polars_dataframe_t}
 end
 
+"""
+    polars_dataframe_new_from_carrow_validated(cfield, carray, validation, out)
+
+Creates a DataFrame as `polars_dataframe_new_from_carrow` does, but reports the reason of failures and runs the checks of `validation` on the array before accepting it. The checks are skipped when `validation` is null. The sizes of the buffers are not part of the Arrow C Data interface, so buffers shorter than their array are not detected.
+
+# Safety The ownership of `carray` is transferred as in `polars_dataframe_new_from_carrow`, it is released when an error is returned.
+"""
+function polars_dataframe_new_from_carrow_validated(cfield, carray, validation, out)
+    @ccall libpolars.polars_dataframe_new_from_carrow_validated(cfield::Ptr{ArrowSchema}, carray::ArrowArray, validation::Ptr{polars_carrow_validation_t}, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
+end
+
+"""
+    polars_dataframe_new_from_carrow_stream(stream, out)
+
+Creates a DataFrame from the record batches of an Arrow C stream, each batch must be a struct array whose fields are the columns and becomes a chunk of the DataFrame. The stream is consumed and released by this function.
+"""
+function polars_dataframe_new_from_carrow_stream(stream, out)
+    @ccall libpolars.polars_dataframe_new_from_carrow_stream(stream::Ptr{ArrowArrayStream}, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
+end
+
 """
     polars_dataframe_schema(df)
 
@@ -114,6 +478,15 @@ function polars_dataframe_schema(df)
     @ccall libpolars.polars_dataframe_schema(df::Ptr{polars_dataframe_t})::ArrowSchema
 end
 
+"""
+    polars_dataframe_export_carrow_stream(df, out)
+
+Exports the DataFrame to `out` according to the Arrow C Stream interface, each chunk of the dataframe is a struct array whose fields are the columns. The chunks are not copied.
+"""
+function polars_dataframe_export_carrow_stream(df, out)
+    @ccall libpolars.polars_dataframe_export_carrow_stream(df::Ptr{polars_dataframe_t}, out::Ptr{ArrowArrayStream})::Ptr{polars_error_t}
+end
+
 function polars_dataframe_new_from_series(series, nseries, out)
     @ccall libpolars.polars_dataframe_new_from_series(series::Ptr{Ptr{polars_series_t}}, nseries::Csize_t, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
 end
@@ -122,26 +495,233 @@ function polars_dataframe_destroy(df)
     @ccall libpolars.polars_dataframe_destroy(df::Ptr{polars_dataframe_t})::Cvoid
 end
 
+"""
+    polars_dataframe_clone(df)
+
+Returns a new handle sharing the columns of `df`, each handle must be destroyed.
+"""
+function polars_dataframe_clone(df)
+    @ccall libpolars.polars_dataframe_clone(df::Ptr{polars_dataframe_t})::Ptr{polars_dataframe_t}
+end
+
 function polars_dataframe_write_parquet(df, user, callback)
     @ccall libpolars.polars_dataframe_write_parquet(df::Ptr{polars_dataframe_t}, user::Ptr{Cvoid}, callback::IOCallback)::Ptr{polars_error_t}
 end
 
+function polars_parquet_write_options_default()
+    @ccall libpolars.polars_parquet_write_options_default()::polars_parquet_write_options_t
+end
+
+function polars_dataframe_write_parquet_with_options(df, options, user, callback)
+    @ccall libpolars.polars_dataframe_write_parquet_with_options(df::Ptr{polars_dataframe_t}, options::Ptr{polars_parquet_write_options_t}, user::Ptr{Cvoid}, callback::IOCallback)::Ptr{polars_error_t}
+end
+
+"""
+    polars_dataframe_write_parquet_partitioned(df, root, rootlen, partition_by, partition_by_lens, n_partition_by, options)
+
+Writes the DataFrame as a hive-partitioned dataset under the directory `root`, with one `key=value/` directory level per column in `partition_by` and one parquet file per partition. Null partition values are written as `__HIVE_DEFAULT_PARTITION__`.
+"""
+function polars_dataframe_write_parquet_partitioned(df, root, rootlen, partition_by, partition_by_lens, n_partition_by, options)
+    @ccall libpolars.polars_dataframe_write_parquet_partitioned(df::Ptr{polars_dataframe_t}, root::Ptr{UInt8}, rootlen::Csize_t, partition_by::Ptr{Ptr{UInt8}}, partition_by_lens::Ptr{Csize_t}, n_partition_by::Csize_t, options::Ptr{polars_parquet_write_options_t})::Ptr{polars_error_t}
+end
+
+function polars_parquet_read_options_default()
+    @ccall libpolars.polars_parquet_read_options_default()::polars_parquet_read_options_t
+end
+
 function polars_dataframe_read_parquet(path, pathlen, out)
     @ccall libpolars.polars_dataframe_read_parquet(path::Ptr{UInt8}, pathlen::Csize_t, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
 end
 
+function polars_dataframe_read_parquet_with_options(path, pathlen, options, out)
+    @ccall libpolars.polars_dataframe_read_parquet_with_options(path::Ptr{UInt8}, pathlen::Csize_t, options::Ptr{polars_parquet_read_options_t}, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
+end
+
+function polars_dataframe_read_parquet_from_callbacks(callbacks, options, out)
+    @ccall libpolars.polars_dataframe_read_parquet_from_callbacks(callbacks::polars_reader_callbacks_t, options::Ptr{polars_parquet_read_options_t}, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
+end
+
+function polars_dataframe_write_ipc(df, compression, user, callback)
+    @ccall libpolars.polars_dataframe_write_ipc(df::Ptr{polars_dataframe_t}, compression::polars_ipc_compression_t, user::Ptr{Cvoid}, callback::IOCallback)::Ptr{polars_error_t}
+end
+
+function polars_ipc_read_options_default()
+    @ccall libpolars.polars_ipc_read_options_default()::polars_ipc_read_options_t
+end
+
+function polars_dataframe_read_ipc(path, pathlen, options, out)
+    @ccall libpolars.polars_dataframe_read_ipc(path::Ptr{UInt8}, pathlen::Csize_t, options::Ptr{polars_ipc_read_options_t}, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
+end
+
+"""
+    polars_dataframe_read_ipc_from_callbacks(callbacks, options, out)
+
+Reads an IPC file through the provided callbacks, memory-mapping is not available in this case and the `memory_map` option is ignored.
+"""
+function polars_dataframe_read_ipc_from_callbacks(callbacks, options, out)
+    @ccall libpolars.polars_dataframe_read_ipc_from_callbacks(callbacks::polars_reader_callbacks_t, options::Ptr{polars_ipc_read_options_t}, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
+end
+
+"""
+    polars_dataframe_read_json(path, pathlen, out)
+
+Reads a JSON file containing a single array of objects, one per row.
+"""
+function polars_dataframe_read_json(path, pathlen, out)
+    @ccall libpolars.polars_dataframe_read_json(path::Ptr{UInt8}, pathlen::Csize_t, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
+end
+
+function polars_dataframe_read_json_from_callbacks(callbacks, out)
+    @ccall libpolars.polars_dataframe_read_json_from_callbacks(callbacks::polars_reader_callbacks_t, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
+end
+
+"""
+    polars_dataframe_read_ndjson(path, pathlen, out)
+
+Reads a newline-delimited JSON file containing one object per line.
+"""
+function polars_dataframe_read_ndjson(path, pathlen, out)
+    @ccall libpolars.polars_dataframe_read_ndjson(path::Ptr{UInt8}, pathlen::Csize_t, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
+end
+
+function polars_dataframe_read_ndjson_from_callbacks(callbacks, out)
+    @ccall libpolars.polars_dataframe_read_ndjson_from_callbacks(callbacks::polars_reader_callbacks_t, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
+end
+
+"""
+    polars_dataframe_write_json(df, user, callback)
+
+Writes the dataframe as a single JSON array containing one object per row.
+"""
+function polars_dataframe_write_json(df, user, callback)
+    @ccall libpolars.polars_dataframe_write_json(df::Ptr{polars_dataframe_t}, user::Ptr{Cvoid}, callback::IOCallback)::Ptr{polars_error_t}
+end
+
+"""
+    polars_dataframe_write_ndjson(df, user, callback)
+
+Writes the dataframe as newline-delimited JSON with one object per line.
+"""
+function polars_dataframe_write_ndjson(df, user, callback)
+    @ccall libpolars.polars_dataframe_write_ndjson(df::Ptr{polars_dataframe_t}, user::Ptr{Cvoid}, callback::IOCallback)::Ptr{polars_error_t}
+end
+
+function polars_csv_read_options_default()
+    @ccall libpolars.polars_csv_read_options_default()::polars_csv_read_options_t
+end
+
+function polars_dataframe_read_csv(path, pathlen, options, out)
+    @ccall libpolars.polars_dataframe_read_csv(path::Ptr{UInt8}, pathlen::Csize_t, options::Ptr{polars_csv_read_options_t}, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
+end
+
+function polars_dataframe_read_csv_from_callbacks(callbacks, options, out)
+    @ccall libpolars.polars_dataframe_read_csv_from_callbacks(callbacks::polars_reader_callbacks_t, options::Ptr{polars_csv_read_options_t}, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
+end
+
+function polars_csv_write_options_default()
+    @ccall libpolars.polars_csv_write_options_default()::polars_csv_write_options_t
+end
+
+function polars_dataframe_write_csv(df, options, user, callback)
+    @ccall libpolars.polars_dataframe_write_csv(df::Ptr{polars_dataframe_t}, options::Ptr{polars_csv_write_options_t}, user::Ptr{Cvoid}, callback::IOCallback)::Ptr{polars_error_t}
+end
+
+"""
+    polars_dataframe_show(df, user, callback)
+
+Writes the dataframe as a table to the callback, callback failures are ignored. Prefer `polars_dataframe_show_checked` which returns them.
+"""
 function polars_dataframe_show(df, user, callback)
     @ccall libpolars.polars_dataframe_show(df::Ptr{polars_dataframe_t}, user::Ptr{Cvoid}, callback::IOCallback)::Cvoid
 end
 
+function polars_dataframe_show_checked(df, user, callback)
+    @ccall libpolars.polars_dataframe_show_checked(df::Ptr{polars_dataframe_t}, user::Ptr{Cvoid}, callback::IOCallback)::Ptr{polars_error_t}
+end
+
 function polars_dataframe_get(df, name, len, out)
     @ccall libpolars.polars_dataframe_get(df::Ptr{polars_dataframe_t}, name::Ptr{UInt8}, len::Csize_t, out::Ptr{Ptr{polars_series_t}})::Ptr{polars_error_t}
 end
 
+"""
+    polars_dataframe_lazy(df)
+
+Returns null on error, prefer `polars_dataframe_lazy_checked` which reports it.
+"""
 function polars_dataframe_lazy(df)
     @ccall libpolars.polars_dataframe_lazy(df::Ptr{polars_dataframe_t})::Ptr{polars_lazy_frame_t}
 end
 
+function polars_dataframe_lazy_checked(df, out)
+    @ccall libpolars.polars_dataframe_lazy_checked(df::Ptr{polars_dataframe_t}, out::Ptr{Ptr{polars_lazy_frame_t}})::Ptr{polars_error_t}
+end
+
+function polars_parquet_scan_options_default()
+    @ccall libpolars.polars_parquet_scan_options_default()::polars_parquet_scan_options_t
+end
+
+"""
+    polars_lazy_frame_scan_parquet(path, pathlen, options, out)
+
+Creates a LazyFrame scanning the parquet file at `path`, the path can also be a glob pattern in which case all the matching files are scanned.
+"""
+function polars_lazy_frame_scan_parquet(path, pathlen, options, out)
+    @ccall libpolars.polars_lazy_frame_scan_parquet(path::Ptr{UInt8}, pathlen::Csize_t, options::Ptr{polars_parquet_scan_options_t}, out::Ptr{Ptr{polars_lazy_frame_t}})::Ptr{polars_error_t}
+end
+
+"""
+    polars_lazy_frame_scan_parquet_files(paths, pathlens, npaths, options, out)
+
+Creates a LazyFrame scanning the union of the parquet files in `paths`, each path can also be a glob pattern. When `hive_partitioning` is set in the options, the `key=value` directories of each file below the non-glob prefix of its pattern are added as typed columns, and the files whose partition values do not match the filters applied to the returned LazyFrame are skipped.
+"""
+function polars_lazy_frame_scan_parquet_files(paths, pathlens, npaths, options, out)
+    @ccall libpolars.polars_lazy_frame_scan_parquet_files(paths::Ptr{Ptr{UInt8}}, pathlens::Ptr{Csize_t}, npaths::Csize_t, options::Ptr{polars_parquet_scan_options_t}, out::Ptr{Ptr{polars_lazy_frame_t}})::Ptr{polars_error_t}
+end
+
+function polars_csv_scan_options_default()
+    @ccall libpolars.polars_csv_scan_options_default()::polars_csv_scan_options_t
+end
+
+"""
+    polars_lazy_frame_scan_csv(path, pathlen, options, out)
+
+Creates a LazyFrame scanning the csv file at `path`, the path can also be a glob pattern in which case all the matching files are scanned.
+"""
+function polars_lazy_frame_scan_csv(path, pathlen, options, out)
+    @ccall libpolars.polars_lazy_frame_scan_csv(path::Ptr{UInt8}, pathlen::Csize_t, options::Ptr{polars_csv_scan_options_t}, out::Ptr{Ptr{polars_lazy_frame_t}})::Ptr{polars_error_t}
+end
+
+"""
+    polars_lazy_frame_scan_csv_files(paths, pathlens, npaths, options, out)
+
+Creates a LazyFrame scanning the union of the csv files in `paths`, the paths and the hive partitions are handled as in `polars_lazy_frame_scan_parquet_files`.
+"""
+function polars_lazy_frame_scan_csv_files(paths, pathlens, npaths, options, out)
+    @ccall libpolars.polars_lazy_frame_scan_csv_files(paths::Ptr{Ptr{UInt8}}, pathlens::Ptr{Csize_t}, npaths::Csize_t, options::Ptr{polars_csv_scan_options_t}, out::Ptr{Ptr{polars_lazy_frame_t}})::Ptr{polars_error_t}
+end
+
+function polars_ndjson_scan_options_default()
+    @ccall libpolars.polars_ndjson_scan_options_default()::polars_ndjson_scan_options_t
+end
+
+"""
+    polars_lazy_frame_scan_ndjson(path, pathlen, options, out)
+
+Creates a LazyFrame scanning the newline-delimited JSON file at `path`, the path can also be a glob pattern in which case all the matching files are scanned.
+"""
+function polars_lazy_frame_scan_ndjson(path, pathlen, options, out)
+    @ccall libpolars.polars_lazy_frame_scan_ndjson(path::Ptr{UInt8}, pathlen::Csize_t, options::Ptr{polars_ndjson_scan_options_t}, out::Ptr{Ptr{polars_lazy_frame_t}})::Ptr{polars_error_t}
+end
+
+"""
+    polars_lazy_frame_scan_ndjson_files(paths, pathlens, npaths, options, out)
+
+Creates a LazyFrame scanning the union of the newline-delimited JSON files in `paths`, the paths and the hive partitions are handled as in `polars_lazy_frame_scan_parquet_files`.
+"""
+function polars_lazy_frame_scan_ndjson_files(paths, pathlens, npaths, options, out)
+    @ccall libpolars.polars_lazy_frame_scan_ndjson_files(paths::Ptr{Ptr{UInt8}}, pathlens::Ptr{Csize_t}, npaths::Csize_t, options::Ptr{polars_ndjson_scan_options_t}, out::Ptr{Ptr{polars_lazy_frame_t}})::Ptr{polars_error_t}
+end
+
 function polars_lazy_frame_destroy(df)
     @ccall libpolars.polars_lazy_frame_destroy(df::Ptr{polars_lazy_frame_t})::Cvoid
 end
@@ -150,38 +730,124 @@ function polars_lazy_frame_clone(df)
     @ccall libpolars.polars_lazy_frame_clone(df::Ptr{polars_lazy_frame_t})::Ptr{polars_lazy_frame_t}
 end
 
+"""
+    polars_lazy_frame_sort(df, exprs, nexprs, descending, nulls_last, maintain_order)
+
+Sorts the LazyFrame in place, errors are ignored. Prefer `polars_lazy_frame_sort_checked` which reports them.
+"""
 function polars_lazy_frame_sort(df, exprs, nexprs, descending, nulls_last, maintain_order)
     @ccall libpolars.polars_lazy_frame_sort(df::Ptr{polars_lazy_frame_t}, exprs::Ptr{Ptr{polars_expr_t}}, nexprs::Csize_t, descending::Ptr{Bool}, nulls_last::Bool, maintain_order::Bool)::Cvoid
 end
 
+"""
+    polars_lazy_frame_sort_checked(df, exprs, nexprs, descending, nulls_last, maintain_order)
+
+Sorts the LazyFrame in place by `exprs`, `descending` has one flag per expression.
+"""
+function polars_lazy_frame_sort_checked(df, exprs, nexprs, descending, nulls_last, maintain_order)
+    @ccall libpolars.polars_lazy_frame_sort_checked(df::Ptr{polars_lazy_frame_t}, exprs::Ptr{Ptr{polars_expr_t}}, nexprs::Csize_t, descending::Ptr{Bool}, nulls_last::Bool, maintain_order::Bool)::Ptr{polars_error_t}
+end
+
 function polars_lazy_frame_concat(lfs, n, out)
     @ccall libpolars.polars_lazy_frame_concat(lfs::Ptr{Ptr{polars_lazy_frame_t}}, n::Csize_t, out::Ptr{Ptr{polars_lazy_frame_t}})::Ptr{polars_error_t}
 end
 
+"""
+    polars_lazy_frame_with_columns(df, exprs, nexprs)
+
+Errors are ignored, prefer `polars_lazy_frame_with_columns_checked` which reports them.
+"""
 function polars_lazy_frame_with_columns(df, exprs, nexprs)
     @ccall libpolars.polars_lazy_frame_with_columns(df::Ptr{polars_lazy_frame_t}, exprs::Ptr{Ptr{polars_expr_t}}, nexprs::Csize_t)::Cvoid
 end
 
+function polars_lazy_frame_with_columns_checked(df, exprs, nexprs)
+    @ccall libpolars.polars_lazy_frame_with_columns_checked(df::Ptr{polars_lazy_frame_t}, exprs::Ptr{Ptr{polars_expr_t}}, nexprs::Csize_t)::Ptr{polars_error_t}
+end
+
+"""
+    polars_lazy_frame_select(df, exprs, nexprs)
+
+Errors are ignored, prefer `polars_lazy_frame_select_checked` which reports them.
+"""
 function polars_lazy_frame_select(df, exprs, nexprs)
     @ccall libpolars.polars_lazy_frame_select(df::Ptr{polars_lazy_frame_t}, exprs::Ptr{Ptr{polars_expr_t}}, nexprs::Csize_t)::Cvoid
 end
 
+function polars_lazy_frame_select_checked(df, exprs, nexprs)
+    @ccall libpolars.polars_lazy_frame_select_checked(df::Ptr{polars_lazy_frame_t}, exprs::Ptr{Ptr{polars_expr_t}}, nexprs::Csize_t)::Ptr{polars_error_t}
+end
+
+"""
+    polars_lazy_frame_filter(df, expr)
+
+Errors are ignored, prefer `polars_lazy_frame_filter_checked` which reports them.
+"""
 function polars_lazy_frame_filter(df, expr)
     @ccall libpolars.polars_lazy_frame_filter(df::Ptr{polars_lazy_frame_t}, expr::Ptr{polars_expr_t})::Cvoid
 end
 
+function polars_lazy_frame_filter_checked(df, expr)
+    @ccall libpolars.polars_lazy_frame_filter_checked(df::Ptr{polars_lazy_frame_t}, expr::Ptr{polars_expr_t})::Ptr{polars_error_t}
+end
+
 function polars_lazy_frame_collect(df, out)
     @ccall libpolars.polars_lazy_frame_collect(df::Ptr{polars_lazy_frame_t}, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
 end
 
+"""
+    polars_lazy_frame_collect_carrow_stream(df, out)
+
+Collects the LazyFrame and exports the result to `out` in the same way as `polars_dataframe_export_carrow_stream`.
+"""
+function polars_lazy_frame_collect_carrow_stream(df, out)
+    @ccall libpolars.polars_lazy_frame_collect_carrow_stream(df::Ptr{polars_lazy_frame_t}, out::Ptr{ArrowArrayStream})::Ptr{polars_error_t}
+end
+
+"""
+    polars_lazy_frame_sink_parquet(df, path, pathlen, options)
+
+Runs the query on the streaming engine and writes the result to the parquet file at `path` without materializing it in memory. Fails if the query cannot be run in a streaming fashion.
+"""
+function polars_lazy_frame_sink_parquet(df, path, pathlen, options)
+    @ccall libpolars.polars_lazy_frame_sink_parquet(df::Ptr{polars_lazy_frame_t}, path::Ptr{UInt8}, pathlen::Csize_t, options::Ptr{polars_parquet_write_options_t})::Ptr{polars_error_t}
+end
+
+"""
+    polars_lazy_frame_sink_ipc(df, path, pathlen, compression)
+
+Runs the query on the streaming engine and writes the result to the IPC file at `path` without materializing it in memory. Fails if the query cannot be run in a streaming fashion.
+"""
+function polars_lazy_frame_sink_ipc(df, path, pathlen, compression)
+    @ccall libpolars.polars_lazy_frame_sink_ipc(df::Ptr{polars_lazy_frame_t}, path::Ptr{UInt8}, pathlen::Csize_t, compression::polars_ipc_compression_t)::Ptr{polars_error_t}
+end
+
+"""
+    polars_lazy_frame_group_by(df, exprs, nexprs)
+
+Returns null on error, prefer `polars_lazy_frame_group_by_checked` which reports it.
+"""
 function polars_lazy_frame_group_by(df, exprs, nexprs)
     @ccall libpolars.polars_lazy_frame_group_by(df::Ptr{polars_lazy_frame_t}, exprs::Ptr{Ptr{polars_expr_t}}, nexprs::Csize_t)::Ptr{polars_lazy_group_by_t}
 end
 
+function polars_lazy_frame_group_by_checked(df, exprs, nexprs, out)
+    @ccall libpolars.polars_lazy_frame_group_by_checked(df::Ptr{polars_lazy_frame_t}, exprs::Ptr{Ptr{polars_expr_t}}, nexprs::Csize_t, out::Ptr{Ptr{polars_lazy_group_by_t}})::Ptr{polars_error_t}
+end
+
+"""
+    polars_lazy_frame_join_inner(a, b, exprs_a, exprs_a_len, exprs_b, exprs_b_len)
+
+Returns null on error, prefer `polars_lazy_frame_join_inner_checked` which reports it.
+"""
 function polars_lazy_frame_join_inner(a, b, exprs_a, exprs_a_len, exprs_b, exprs_b_len)
     @ccall libpolars.polars_lazy_frame_join_inner(a::Ptr{polars_lazy_frame_t}, b::Ptr{polars_lazy_frame_t}, exprs_a::Ptr{Ptr{polars_expr_t}}, exprs_a_len::Csize_t, exprs_b::Ptr{Ptr{polars_expr_t}}, exprs_b_len::Csize_t)::Ptr{polars_lazy_frame_t}
 end
 
+function polars_lazy_frame_join_inner_checked(a, b, exprs_a, exprs_a_len, exprs_b, exprs_b_len, out)
+    @ccall libpolars.polars_lazy_frame_join_inner_checked(a::Ptr{polars_lazy_frame_t}, b::Ptr{polars_lazy_frame_t}, exprs_a::Ptr{Ptr{polars_expr_t}}, exprs_a_len::Csize_t, exprs_b::Ptr{Ptr{polars_expr_t}}, exprs_b_len::Csize_t, out::Ptr{Ptr{polars_lazy_frame_t}})::Ptr{polars_error_t}
+end
+
 function polars_lazy_frame_fetch(df, n, out)
     @ccall libpolars.polars_lazy_frame_fetch(df::Ptr{polars_lazy_frame_t}, n::Csize_t, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
 end
@@ -190,14 +856,58 @@ function polars_lazy_group_by_destroy(gb)
     @ccall libpolars.polars_lazy_group_by_destroy(gb::Ptr{polars_lazy_group_by_t})::Cvoid
 end
 
+"""
+    polars_lazy_group_by_agg(gb, exprs, nexprs)
+
+Returns null on error, prefer `polars_lazy_group_by_agg_checked` which reports it.
+"""
 function polars_lazy_group_by_agg(gb, exprs, nexprs)
     @ccall libpolars.polars_lazy_group_by_agg(gb::Ptr{polars_lazy_group_by_t}, exprs::Ptr{Ptr{polars_expr_t}}, nexprs::Csize_t)::Ptr{polars_lazy_frame_t}
 end
 
+function polars_lazy_group_by_agg_checked(gb, exprs, nexprs, out)
+    @ccall libpolars.polars_lazy_group_by_agg_checked(gb::Ptr{polars_lazy_group_by_t}, exprs::Ptr{Ptr{polars_expr_t}}, nexprs::Csize_t, out::Ptr{Ptr{polars_lazy_frame_t}})::Ptr{polars_error_t}
+end
+
+"""
+    polars_csv_batched_reader_open(path, pathlen, options, out)
+
+Opens the CSV file at `path` for reading in batches, the schema is inferred when opening the reader unless overridden by the options.
+"""
+function polars_csv_batched_reader_open(path, pathlen, options, out)
+    @ccall libpolars.polars_csv_batched_reader_open(path::Ptr{UInt8}, pathlen::Csize_t, options::Ptr{polars_csv_read_options_t}, out::Ptr{Ptr{polars_csv_batched_reader_t}})::Ptr{polars_error_t}
+end
+
+function polars_csv_batched_reader_open_from_callbacks(callbacks, options, out)
+    @ccall libpolars.polars_csv_batched_reader_open_from_callbacks(callbacks::polars_reader_callbacks_t, options::Ptr{polars_csv_read_options_t}, out::Ptr{Ptr{polars_csv_batched_reader_t}})::Ptr{polars_error_t}
+end
+
+function polars_csv_batched_reader_destroy(reader)
+    @ccall libpolars.polars_csv_batched_reader_destroy(reader::Ptr{polars_csv_batched_reader_t})::Cvoid
+end
+
+"""
+    polars_csv_batched_reader_next_batches(reader, n, out, out_len)
+
+Reads up to `n` DataFrames from the file and writes them to `out`, which must have room for `n` handles. `out_len` is set to the number of DataFrames written, it is 0 once the file is exhausted.
+"""
+function polars_csv_batched_reader_next_batches(reader, n, out, out_len)
+    @ccall libpolars.polars_csv_batched_reader_next_batches(reader::Ptr{polars_csv_batched_reader_t}, n::Csize_t, out::Ptr{Ptr{polars_dataframe_t}}, out_len::Ptr{Csize_t})::Ptr{polars_error_t}
+end
+
 function polars_expr_destroy(expr)
     @ccall libpolars.polars_expr_destroy(expr::Ptr{polars_expr_t})::Cvoid
 end
 
+"""
+    polars_expr_clone(expr)
+
+Returns a copy of `expr`, the whole expression tree is copied so the cost grows with its size. Both expressions are owned by the caller and must be destroyed with `polars_expr_destroy`.
+"""
+function polars_expr_clone(expr)
+    @ccall libpolars.polars_expr_clone(expr::Ptr{polars_expr_t})::Ptr{polars_expr_t}
+end
+
 function polars_expr_literal_bool(value)
     @ccall libpolars.polars_expr_literal_bool(value::Bool)::Ptr{polars_expr_t}
 end
@@ -254,6 +964,15 @@ function polars_expr_cast(expr, dtype)
     @ccall libpolars.polars_expr_cast(expr::Ptr{polars_expr_t}, dtype::polars_value_type_t)::Ptr{polars_expr_t}
 end
 
+"""
+    polars_expr_cast_checked(expr, dtype, out)
+
+Casts the expression to `dtype`, which cannot be a list or a struct type.
+"""
+function polars_expr_cast_checked(expr, dtype, out)
+    @ccall libpolars.polars_expr_cast_checked(expr::Ptr{polars_expr_t}, dtype::polars_value_type_t, out::Ptr{Ptr{polars_expr_t}})::Ptr{polars_error_t}
+end
+
 function polars_expr_keep_name(expr)
     @ccall libpolars.polars_expr_keep_name(expr::Ptr{polars_expr_t})::Ptr{polars_expr_t}
 end
@@ -538,22 +1257,174 @@ function polars_expr_str_contains_literal(a, b)
     @ccall libpolars.polars_expr_str_contains_literal(a::Ptr{polars_expr_t}, b::Ptr{polars_expr_t})::Ptr{polars_expr_t}
 end
 
+"""
+    polars_expr_struct_field_by_name(a, name, len)
+
+Returns null when `name` is not valid UTF-8, prefer `polars_expr_struct_field_by_name_checked` which reports the error.
+"""
 function polars_expr_struct_field_by_name(a, name, len)
     @ccall libpolars.polars_expr_struct_field_by_name(a::Ptr{polars_expr_t}, name::Ptr{UInt8}, len::Csize_t)::Ptr{polars_expr_t}
 end
 
+function polars_expr_struct_field_by_name_checked(a, name, len, out)
+    @ccall libpolars.polars_expr_struct_field_by_name_checked(a::Ptr{polars_expr_t}, name::Ptr{UInt8}, len::Csize_t, out::Ptr{Ptr{polars_expr_t}})::Ptr{polars_error_t}
+end
+
 function polars_expr_struct_field_by_index(a, fieldidx)
     @ccall libpolars.polars_expr_struct_field_by_index(a::Ptr{polars_expr_t}, fieldidx::Int64)::Ptr{polars_expr_t}
 end
 
+"""
+    polars_expr_struct_field_by_index_checked(a, fieldidx, out)
+
+Returns the field at `fieldidx` of the struct expression, negative indices count from the last field.
+"""
+function polars_expr_struct_field_by_index_checked(a, fieldidx, out)
+    @ccall libpolars.polars_expr_struct_field_by_index_checked(a::Ptr{polars_expr_t}, fieldidx::Int64, out::Ptr{Ptr{polars_expr_t}})::Ptr{polars_error_t}
+end
+
+"""
+    polars_expr_struct_rename_fields(a, names, lens, num_names)
+
+Returns null when one of the names is not valid UTF-8, prefer `polars_expr_struct_rename_fields_checked` which reports the error.
+"""
 function polars_expr_struct_rename_fields(a, names, lens, num_names)
     @ccall libpolars.polars_expr_struct_rename_fields(a::Ptr{polars_expr_t}, names::Ptr{Ptr{UInt8}}, lens::Ptr{Csize_t}, num_names::Csize_t)::Ptr{polars_expr_t}
 end
 
+function polars_expr_struct_rename_fields_checked(a, names, lens, num_names, out)
+    @ccall libpolars.polars_expr_struct_rename_fields_checked(a::Ptr{polars_expr_t}, names::Ptr{Ptr{UInt8}}, lens::Ptr{Csize_t}, num_names::Csize_t, out::Ptr{Ptr{polars_expr_t}})::Ptr{polars_error_t}
+end
+
+"""
+    polars_parquet_metadata_read(path, pathlen, out)
+
+Reads the metadata of the parquet file at `path` without reading its data.
+"""
+function polars_parquet_metadata_read(path, pathlen, out)
+    @ccall libpolars.polars_parquet_metadata_read(path::Ptr{UInt8}, pathlen::Csize_t, out::Ptr{Ptr{polars_parquet_metadata_t}})::Ptr{polars_error_t}
+end
+
+function polars_parquet_metadata_read_from_callbacks(callbacks, out)
+    @ccall libpolars.polars_parquet_metadata_read_from_callbacks(callbacks::polars_reader_callbacks_t, out::Ptr{Ptr{polars_parquet_metadata_t}})::Ptr{polars_error_t}
+end
+
+function polars_parquet_metadata_destroy(metadata)
+    @ccall libpolars.polars_parquet_metadata_destroy(metadata::Ptr{polars_parquet_metadata_t})::Cvoid
+end
+
+function polars_parquet_metadata_num_rows(metadata)
+    @ccall libpolars.polars_parquet_metadata_num_rows(metadata::Ptr{polars_parquet_metadata_t})::Csize_t
+end
+
+function polars_parquet_metadata_num_row_groups(metadata)
+    @ccall libpolars.polars_parquet_metadata_num_row_groups(metadata::Ptr{polars_parquet_metadata_t})::Csize_t
+end
+
+function polars_parquet_metadata_row_group_num_rows(metadata, index, out)
+    @ccall libpolars.polars_parquet_metadata_row_group_num_rows(metadata::Ptr{polars_parquet_metadata_t}, index::Csize_t, out::Ptr{Csize_t})::Ptr{polars_error_t}
+end
+
+"""
+    polars_parquet_metadata_row_group_byte_size(metadata, index, out)
+
+Returns the total uncompressed size in bytes of the row group at `index`.
+"""
+function polars_parquet_metadata_row_group_byte_size(metadata, index, out)
+    @ccall libpolars.polars_parquet_metadata_row_group_byte_size(metadata::Ptr{polars_parquet_metadata_t}, index::Csize_t, out::Ptr{Csize_t})::Ptr{polars_error_t}
+end
+
+"""
+    polars_parquet_metadata_schema(metadata, out)
+
+Writes an [`ArrowSchema`](@ref) describing the file's schema according to Arrow C Data interface, the schema is a struct whose fields are the columns as in `polars_dataframe_schema`.
+"""
+function polars_parquet_metadata_schema(metadata, out)
+    @ccall libpolars.polars_parquet_metadata_schema(metadata::Ptr{polars_parquet_metadata_t}, out::Ptr{ArrowSchema})::Ptr{polars_error_t}
+end
+
+function polars_parquet_metadata_num_key_values(metadata)
+    @ccall libpolars.polars_parquet_metadata_num_key_values(metadata::Ptr{polars_parquet_metadata_t})::Csize_t
+end
+
+"""
+    polars_parquet_metadata_key_value(metadata, index, key, keylen, value, valuelen)
+
+Returns the key and value of the key-value metadata entry at `index`, the value is set to null with a length of 0 when the entry has no value. The returned strings are owned by the metadata.
+"""
+function polars_parquet_metadata_key_value(metadata, index, key, keylen, value, valuelen)
+    @ccall libpolars.polars_parquet_metadata_key_value(metadata::Ptr{polars_parquet_metadata_t}, index::Csize_t, key::Ptr{Ptr{UInt8}}, keylen::Ptr{Csize_t}, value::Ptr{Ptr{UInt8}}, valuelen::Ptr{Csize_t})::Ptr{polars_error_t}
+end
+
+"""
+    polars_parquet_metadata_statistics(metadata, name, len, out)
+
+Returns the statistics of the column `name` as a DataFrame with the columns `null_count`, `distinct_count`, `min` and `max` and one row per row group. Values are null when the statistic was not written.
+"""
+function polars_parquet_metadata_statistics(metadata, name, len, out)
+    @ccall libpolars.polars_parquet_metadata_statistics(metadata::Ptr{polars_parquet_metadata_t}, name::Ptr{UInt8}, len::Csize_t, out::Ptr{Ptr{polars_dataframe_t}})::Ptr{polars_error_t}
+end
+
+"""
+    polars_parquet_batched_writer_open(schema, options, user, callback, out)
+
+Opens a parquet writer writing to `callback`, the file schema is given by `schema` which describes a struct whose fields are the columns as returned by `polars_dataframe_schema`.
+"""
+function polars_parquet_batched_writer_open(schema, options, user, callback, out)
+    @ccall libpolars.polars_parquet_batched_writer_open(schema::Ptr{ArrowSchema}, options::Ptr{polars_parquet_write_options_t}, user::Ptr{Cvoid}, callback::IOCallback, out::Ptr{Ptr{polars_parquet_batched_writer_t}})::Ptr{polars_error_t}
+end
+
+"""
+    polars_parquet_batched_writer_write_batch(writer, df)
+
+Writes the rows of `df` to the file, `df` must have the schema the writer was opened with.
+"""
+function polars_parquet_batched_writer_write_batch(writer, df)
+    @ccall libpolars.polars_parquet_batched_writer_write_batch(writer::Ptr{polars_parquet_batched_writer_t}, df::Ptr{polars_dataframe_t})::Ptr{polars_error_t}
+end
+
+"""
+    polars_parquet_batched_writer_finish(writer)
+
+Writes the footer of the file, no batch can be written afterwards. The writer must still be destroyed with `polars_parquet_batched_writer_destroy`.
+"""
+function polars_parquet_batched_writer_finish(writer)
+    @ccall libpolars.polars_parquet_batched_writer_finish(writer::Ptr{polars_parquet_batched_writer_t})::Ptr{polars_error_t}
+end
+
+"""
+    polars_parquet_batched_writer_destroy(writer)
+
+Destroys the writer, the footer is not written if `polars_parquet_batched_writer_finish` was not called.
+"""
+function polars_parquet_batched_writer_destroy(writer)
+    @ccall libpolars.polars_parquet_batched_writer_destroy(writer::Ptr{polars_parquet_batched_writer_t})::Cvoid
+end
+
+"""
+    polars_series_new_from_carrow(name, namelen, schema, array, out)
+
+Creates a series named `name` from an array according to the Arrow C Data interface.
+
+# Safety The ownership of `array` is transferred to the series as in `polars_dataframe_new_from_carrow`, the caller should *not* release it.
+"""
+function polars_series_new_from_carrow(name, namelen, schema, array, out)
+    @ccall libpolars.polars_series_new_from_carrow(name::Ptr{UInt8}, namelen::Csize_t, schema::Ptr{ArrowSchema}, array::ArrowArray, out::Ptr{Ptr{polars_series_t}})::Ptr{polars_error_t}
+end
+
 function polars_series_destroy(series)
     @ccall libpolars.polars_series_destroy(series::Ptr{polars_series_t})::Cvoid
 end
 
+"""
+    polars_series_clone(series)
+
+Returns a new handle sharing the data of `series`, each handle must be destroyed.
+"""
+function polars_series_clone(series)
+    @ccall libpolars.polars_series_clone(series::Ptr{polars_series_t})::Ptr{polars_series_t}
+end
+
 function polars_series_type(series)
     @ccall libpolars.polars_series_type(series::Ptr{polars_series_t})::polars_value_type_t
 end
@@ -570,23 +1441,49 @@ function polars_series_schema(series)
     @ccall libpolars.polars_series_schema(series::Ptr{polars_series_t})::ArrowSchema
 end
 
+function polars_series_num_chunks(series)
+    @ccall libpolars.polars_series_num_chunks(series::Ptr{polars_series_t})::Csize_t
+end
+
+"""
+    polars_series_export_carrow(series, index, out)
+
+Exports the chunk at `index` to `out` according to the Arrow C Data interface, its type matches the field returned by `polars_series_schema`. The buffers are shared with the series and kept alive until the array is released by the caller.
+"""
+function polars_series_export_carrow(series, index, out)
+    @ccall libpolars.polars_series_export_carrow(series::Ptr{polars_series_t}, index::Csize_t, out::Ptr{ArrowArray})::Ptr{polars_error_t}
+end
+
 """
     polars_series_is_null(series, index)
 
-Returns whether or not the value at index `index` is null, return false if the index is out of bounds.
+Returns whether or not the value at index `index` is null, return false if the index is out of bounds. Prefer `polars_series_is_null_checked` which reports it.
 """
 function polars_series_is_null(series, index)
     @ccall libpolars.polars_series_is_null(series::Ptr{polars_series_t}, index::Csize_t)::Bool
 end
 
+function polars_series_is_null_checked(series, index, out)
+    @ccall libpolars.polars_series_is_null_checked(series::Ptr{polars_series_t}, index::Csize_t, out::Ptr{Bool})::Ptr{polars_error_t}
+end
+
 function polars_series_name(series, out)
     @ccall libpolars.polars_series_name(series::Ptr{polars_series_t}, out::Ptr{Ptr{UInt8}})::Csize_t
 end
 
+"""
+    polars_series_get(series, index)
+
+Returns the value at `index` or null on error, prefer `polars_series_get_checked` which reports it.
+"""
 function polars_series_get(series, index)
     @ccall libpolars.polars_series_get(series::Ptr{polars_series_t}, index::Csize_t)::Ptr{polars_value_t}
 end
 
+function polars_series_get_checked(series, index, out)
+    @ccall libpolars.polars_series_get_checked(series::Ptr{polars_series_t}, index::Csize_t, out::Ptr{Ptr{polars_value_t}})::Ptr{polars_error_t}
+end
+
 function polars_series_get_bool(series, index, out)
     @ccall libpolars.polars_series_get_bool(series::Ptr{polars_series_t}, index::Csize_t, out::Ptr{Bool})::Ptr{polars_error_t}
 end
@@ -631,6 +1528,10 @@ function polars_series_get_f64(series, index, out)
     @ccall libpolars.polars_series_get_f64(series::Ptr{polars_series_t}, index::Csize_t, out::Ptr{Cdouble})::Ptr{polars_error_t}
 end
 
+function polars_carrow_validation_default()
+    @ccall libpolars.polars_carrow_validation_default()::polars_carrow_validation_t
+end
+
 function polars_value_type(value)
     @ccall libpolars.polars_value_type(value::Ptr{polars_value_t})::polars_value_type_t
 end
@@ -704,15 +1605,20 @@ end
     polars_value_struct_get(value, fieldidx, out)
 
 Used to get value of of a Struct value fields.
-
-NOTE: The value producing the new value must outlive the value from the field.
-
-Safety: Values lifetimes must be valid and only support physical dtypes for now.
 """
 function polars_value_struct_get(value, fieldidx, out)
     @ccall libpolars.polars_value_struct_get(value::Ptr{polars_value_t}, fieldidx::Csize_t, out::Ptr{Ptr{polars_value_t}})::Ptr{polars_error_t}
 end
 
+"""
+    polars_value_time_zone(value, out)
+
+Returns the time zone of a datetime value, the time zone is set to null with a length of 0 when the value has none. The returned string is owned by the value.
+"""
+function polars_value_time_zone(value, out)
+    @ccall libpolars.polars_value_time_zone(value::Ptr{polars_value_t}, out::Ptr{Ptr{UInt8}})::Csize_t
+end
+
 """
     polars_value_list_type(value)
 
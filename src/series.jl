@@ -66,7 +66,10 @@ function Base.getindex(series::Series{MT}, index) where {MT<:Union{MaybeMissing{
 
     T = nomissing(MT)
 
-    value_at_index = Value{T}(polars_series_get(series, index), series)
+    out = Ref{Ptr{polars_value_t}}()
+    err = polars_series_get_checked(series, index, out)
+    polars_error(err)
+    value_at_index = Value{T}(out[], series)
 
     load_value(value_at_index)
 end
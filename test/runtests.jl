@@ -1,4 +1,87 @@
 using Polars, Test
+using Polars.API
+
+"Returns a copy of the options `x` with the fields given as keywords replaced."
+function with_options(x::T; kw...) where {T}
+    T((get(kw, name, getfield(x, name)) for name in fieldnames(T))...)
+end
+
+"Calls `f` with the pointers, lengths and count of `strings` as expected by the C API."
+function with_strings(f, strings)
+    ptrs = map(pointer, strings)
+    lens = Csize_t[sizeof(s) for s in strings]
+    GC.@preserve strings ptrs lens f(pointer(ptrs), pointer(lens), length(strings))
+end
+
+"Calls the C function `f` with `args` and an out pointer, returning the DataFrame."
+function read_frame(f, args...)
+    out = Ref{Ptr{polars_dataframe_t}}()
+    err = f(args..., out)
+    Polars.polars_error(err)
+    DataFrame(out[])
+end
+
+"Calls the C function `f` with `args` and an out pointer, returning the LazyFrame."
+function scan_frame(f, args...)
+    out = Ref{Ptr{polars_lazy_frame_t}}()
+    err = f(args..., out)
+    Polars.polars_error(err)
+    Polars.LazyFrame(out[])
+end
+
+function _read_callback(user, data, len)
+    try
+        Cssize_t(readbytes!(user[], unsafe_wrap(Array, data, len)))
+    catch
+        Cssize_t(-1)
+    end
+end
+
+function _seek_callback(user, offset, whence)
+    io = user[]
+    try
+        if whence == 0
+            seek(io, offset)
+        elseif whence == 1
+            skip(io, offset)
+        else
+            seekend(io)
+            skip(io, offset)
+        end
+        Int64(position(io))
+    catch
+        Int64(-1)
+    end
+end
+
+_tell_callback(user) = Int64(position(user[]))
+
+"Returns reader callbacks over the IO in `ref`, which must be preserved while they are used."
+function reader_callbacks(ref::Ref{<:IO})
+    polars_reader_callbacks_t(
+        pointer_from_objref(ref),
+        @cfunction(_read_callback, Cssize_t, (Any, Ptr{UInt8}, Csize_t)),
+        @cfunction(_seek_callback, Int64, (Any, Int64, Int32)),
+        @cfunction(_tell_callback, Int64, (Any,)),
+    )
+end
+
+"Returns the kind of the error `err` and destroys it."
+function error_kind(err::Ptr{polars_error_t})
+    @assert err != C_NULL
+    kind = polars_error_kind(err)
+    polars_error_destroy(err)
+    kind
+end
+
+"Calls the C writer `f` with `args` and a callback writing to a buffer, returning the bytes."
+function write_frame(f, args...)
+    io = IOBuffer()
+    callback = @cfunction(Polars._write_callback, Cssize_t, (Any, Ptr{Cchar}, Cuint))
+    err = f(args..., Ref(io), callback)
+    Polars.polars_error(err)
+    take!(io)
+end
 
 @testset "Create from C Data interface" begin
     table = (; x = randn(Float32, 100))
@@ -62,3 +145,694 @@ end
     s = Series(:values, values)
     @test sum(values) == sum(s)
 end
+
+@testset "Read CSV" begin
+    path = joinpath(mktempdir(), "data.csv")
+    write(path, "x;name\n1;a\nNA;b\n3;NA\n")
+
+    df = with_strings(["NA"]) do null_values, null_values_lens, n_null_values
+        options = with_options(polars_csv_read_options_default();
+                               separator=UInt8(';'),
+                               null_values, null_values_lens, n_null_values)
+        read_frame(polars_dataframe_read_csv, path, sizeof(path), Ref(options))
+    end
+
+    @test size(df) == (3, 2)
+    @test isequal(collect(df[:x]), [1, missing, 3])
+    @test isequal(collect(df[:name]), ["a", "b", missing])
+
+    options = with_options(polars_csv_read_options_default(); has_header=false, skip_rows=1)
+    df = read_frame(polars_dataframe_read_csv, path, sizeof(path), Ref(options))
+    @test size(df) == (3, 1)
+end
+
+@testset "Write CSV" begin
+    df = DataFrame((; x=[1, 2, missing], name=["a", "b;c", "d"]))
+
+    null_value = "NULL"
+    options = with_options(polars_csv_write_options_default();
+                           separator=UInt8(';'),
+                           null_value=pointer(null_value),
+                           null_value_len=sizeof(null_value))
+    csv = GC.@preserve null_value String(write_frame(polars_dataframe_write_csv, df, Ref(options)))
+
+    @test startswith(csv, "x;name\n1;a\n")
+    @test occursin("\"b;c\"", csv)
+    @test occursin("NULL;d", csv)
+
+    path = joinpath(mktempdir(), "data.csv")
+    write(path, csv)
+    df2 = with_strings(["NULL"]) do null_values, null_values_lens, n_null_values
+        options = with_options(polars_csv_read_options_default();
+                               separator=UInt8(';'),
+                               null_values, null_values_lens, n_null_values)
+        read_frame(polars_dataframe_read_csv, path, sizeof(path), Ref(options))
+    end
+
+    @test isequal(collect(df2[:x]), collect(df[:x]))
+    @test collect(df2[:name]) == collect(df[:name])
+end
+
+@testset "Scan parquet" begin
+    path = joinpath(mktempdir(), "data.parquet")
+    df = DataFrame((; x=[1, 2, 3, 4], y=[1.5, 2.5, 3.5, 4.5]))
+    write_parquet(path, df)
+
+    row_count_name = "row"
+    options = with_options(polars_parquet_scan_options_default();
+                           n_rows=3,
+                           row_count_name=pointer(row_count_name),
+                           row_count_name_len=sizeof(row_count_name))
+    lf = GC.@preserve row_count_name scan_frame(polars_lazy_frame_scan_parquet, path, sizeof(path), Ref(options))
+    df2 = filter(lf, col("x") > 1) |> collect
+
+    @test size(df2) == (2, 3)
+    @test collect(df2[:row]) == [1, 2]
+    @test collect(df2[:y]) == [2.5, 3.5]
+end
+
+@testset "Scan CSV" begin
+    dir = mktempdir()
+    path = joinpath(dir, "data.csv")
+    write(path, "# generated\nx,name\n1,a\n2,b\n3,c\n")
+
+    dtypes = [PolarsValueTypeFloat64]
+    lf = with_strings(["x"]) do dtypes_names, dtypes_names_lens, n_dtypes
+        options = with_options(polars_csv_scan_options_default();
+                               comment_char=UInt8('#'),
+                               n_rows=2,
+                               dtypes_names, dtypes_names_lens, n_dtypes,
+                               dtypes=pointer(dtypes))
+        GC.@preserve dtypes scan_frame(polars_lazy_frame_scan_csv, path, sizeof(path), Ref(options))
+    end
+    df = collect(lf)
+
+    @test size(df) == (2, 2)
+    @test eltype(df[:x]) == Float64
+    @test collect(df[:x]) == [1.0, 2.0]
+    @test collect(df[:name]) == ["a", "b"]
+
+    pattern = joinpath(dir, "*.csv")
+    write(joinpath(dir, "more.csv"), "x,name\n4,d\n")
+    options = with_options(polars_csv_scan_options_default(); comment_char=UInt8('#'))
+    df = scan_frame(polars_lazy_frame_scan_csv, pattern, sizeof(pattern), Ref(options)) |> collect
+    @test sort(collect(df[:x])) == [1, 2, 3, 4]
+end
+
+@testset "IPC" begin
+    df = DataFrame((; x=[1, 2, 3], name=["a", missing, "c"]))
+    path = joinpath(mktempdir(), "data.arrow")
+
+    for compression in (PolarsIpcCompressionZSTD, PolarsIpcCompressionLZ4, PolarsIpcCompressionUncompressed)
+        write(path, write_frame(polars_dataframe_write_ipc, df, compression))
+
+        options = with_options(polars_ipc_read_options_default(); memory_map=false)
+        df2 = read_frame(polars_dataframe_read_ipc, path, sizeof(path), Ref(options))
+        @test size(df2) == (3, 2)
+        @test collect(df2[:x]) == [1, 2, 3]
+        @test isequal(collect(df2[:name]), ["a", missing, "c"])
+    end
+
+    df2 = with_strings(["name"]) do columns, columns_lens, n_columns
+        options = with_options(polars_ipc_read_options_default(); columns, columns_lens, n_columns)
+        read_frame(polars_dataframe_read_ipc, path, sizeof(path), Ref(options))
+    end
+    @test size(df2) == (3, 1)
+end
+
+@testset "JSON" begin
+    df = DataFrame((; x=[1, 2, 3], name=["a", missing, "c"]))
+    dir = mktempdir()
+
+    path = joinpath(dir, "data.json")
+    write(path, write_frame(polars_dataframe_write_json, df))
+    df2 = read_frame(polars_dataframe_read_json, path, sizeof(path))
+    @test collect(df2[:x]) == [1, 2, 3]
+    @test isequal(collect(df2[:name]), ["a", missing, "c"])
+
+    path = joinpath(dir, "data.ndjson")
+    write(path, write_frame(polars_dataframe_write_ndjson, df))
+    @test countlines(path) == 3
+    df2 = read_frame(polars_dataframe_read_ndjson, path, sizeof(path))
+    @test collect(df2[:x]) == [1, 2, 3]
+    @test isequal(collect(df2[:name]), ["a", missing, "c"])
+
+    options = with_options(polars_ndjson_scan_options_default(); n_rows=2)
+    df2 = scan_frame(polars_lazy_frame_scan_ndjson, path, sizeof(path), Ref(options)) |> collect
+    @test collect(df2[:x]) == [1, 2]
+end
+
+@testset "Read from callbacks" begin
+    df = DataFrame((; x=[1, 2, 3], name=["a", missing, "c"]))
+
+    function read_from(f, bytes, args...)
+        ref = Ref(IOBuffer(bytes))
+        GC.@preserve ref read_frame(f, reader_callbacks(ref), args...)
+    end
+
+    csv = write_frame(polars_dataframe_write_csv, df, Ref(polars_csv_write_options_default()))
+    df2 = read_from(polars_dataframe_read_csv_from_callbacks, csv, Ref(polars_csv_read_options_default()))
+    @test collect(df2[:x]) == [1, 2, 3]
+
+    ipc = write_frame(polars_dataframe_write_ipc, df, PolarsIpcCompressionUncompressed)
+    df2 = read_from(polars_dataframe_read_ipc_from_callbacks, ipc, Ref(polars_ipc_read_options_default()))
+    @test isequal(collect(df2[:name]), ["a", missing, "c"])
+
+    json = write_frame(polars_dataframe_write_json, df)
+    df2 = read_from(polars_dataframe_read_json_from_callbacks, json)
+    @test collect(df2[:x]) == [1, 2, 3]
+
+    ndjson = write_frame(polars_dataframe_write_ndjson, df)
+    df2 = read_from(polars_dataframe_read_ndjson_from_callbacks, ndjson)
+    @test collect(df2[:x]) == [1, 2, 3]
+
+    # A failing read callback is reported as an error.
+    ref = Ref(IOBuffer(csv; read=false))
+    out = Ref{Ptr{polars_dataframe_t}}()
+    err = GC.@preserve ref polars_dataframe_read_csv_from_callbacks(
+        reader_callbacks(ref), Ref(polars_csv_read_options_default()), out)
+    @test err != C_NULL
+    polars_error_destroy(err)
+end
+
+@testset "Write parquet with options" begin
+    df = DataFrame((; x=collect(1:10), y=randn(10)))
+    path = joinpath(mktempdir(), "data.parquet")
+
+    for compression in (PolarsParquetCompressionUncompressed, PolarsParquetCompressionSnappy,
+                        PolarsParquetCompressionGzip, PolarsParquetCompressionZstd)
+        options = with_options(polars_parquet_write_options_default();
+                               compression, compression_level=3, statistics=true, row_group_size=4)
+        write(path, write_frame(polars_dataframe_write_parquet_with_options, df, Ref(options)))
+
+        df2 = read_parquet(path)
+        @test collect(df2[:x]) == collect(df[:x])
+        @test collect(df2[:y]) == collect(df[:y])
+    end
+
+    options = with_options(polars_parquet_write_options_default();
+                           compression=PolarsParquetCompressionGzip, compression_level=100)
+    @test_throws ErrorException write_frame(polars_dataframe_write_parquet_with_options, df, Ref(options))
+end
+
+@testset "Read parquet with options" begin
+    df = DataFrame((; x=collect(1:10), y=collect(11:20), z=collect(21:30)))
+    path = joinpath(mktempdir(), "data.parquet")
+    write_parquet(path, df)
+
+    projection = Csize_t[0, 2]
+    row_count_name = "row"
+    options = with_options(polars_parquet_read_options_default();
+                           projection=pointer(projection), n_projection=length(projection),
+                           n_rows=4,
+                           row_count_name=pointer(row_count_name),
+                           row_count_name_len=sizeof(row_count_name),
+                           row_count_offset=10,
+                           parallel=PolarsParallelStrategyNone)
+    df2 = GC.@preserve projection row_count_name begin
+        read_frame(polars_dataframe_read_parquet_with_options, path, sizeof(path), Ref(options))
+    end
+    @test size(df2) == (4, 3)
+    @test collect(df2[:row]) == 10:13
+    @test collect(df2[:z]) == 21:24
+
+    df2 = with_strings(["y"]) do columns, columns_lens, n_columns
+        options = with_options(polars_parquet_read_options_default(); columns, columns_lens, n_columns)
+        read_frame(polars_dataframe_read_parquet_with_options, path, sizeof(path), Ref(options))
+    end
+    @test size(df2) == (10, 1)
+    @test collect(df2[:y]) == 11:20
+
+    ref = Ref(IOBuffer(read(path)))
+    df2 = GC.@preserve ref begin
+        read_frame(polars_dataframe_read_parquet_from_callbacks, reader_callbacks(ref),
+                   Ref(polars_parquet_read_options_default()))
+    end
+    @test collect(df2[:x]) == 1:10
+end
+
+@testset "Sink lazy frames" begin
+    dir = mktempdir()
+    path = joinpath(dir, "data.parquet")
+    write_parquet(path, DataFrame((; x=collect(1:10))))
+
+    options = polars_parquet_scan_options_default()
+    lf = filter(scan_frame(polars_lazy_frame_scan_parquet, path, sizeof(path), Ref(options)), col("x") > 5)
+
+    out = joinpath(dir, "sink.parquet")
+    options = polars_parquet_write_options_default()
+    Polars.polars_error(polars_lazy_frame_sink_parquet(lf, out, sizeof(out), Ref(options)))
+    @test collect(read_parquet(out)[:x]) == 6:10
+
+    out = joinpath(dir, "sink.arrow")
+    Polars.polars_error(polars_lazy_frame_sink_ipc(lf, out, sizeof(out), PolarsIpcCompressionLZ4))
+    options = with_options(polars_ipc_read_options_default(); memory_map=false)
+    df = read_frame(polars_dataframe_read_ipc, out, sizeof(out), Ref(options))
+    @test collect(df[:x]) == 6:10
+end
+
+@testset "Parquet metadata" begin
+    df = DataFrame((; x=collect(1:10), name=string.('a':'j')))
+    path = joinpath(mktempdir(), "data.parquet")
+    options = with_options(polars_parquet_write_options_default(); statistics=true, row_group_size=4)
+    write(path, write_frame(polars_dataframe_write_parquet_with_options, df, Ref(options)))
+
+    out = Ref{Ptr{polars_parquet_metadata_t}}()
+    Polars.polars_error(polars_parquet_metadata_read(path, sizeof(path), out))
+    metadata = out[]
+
+    @test polars_parquet_metadata_num_rows(metadata) == 10
+    ngroups = polars_parquet_metadata_num_row_groups(metadata)
+    @test ngroups > 1
+
+    nrows = map(0:ngroups-1) do index
+        rows = Ref{Csize_t}()
+        Polars.polars_error(polars_parquet_metadata_row_group_num_rows(metadata, index, rows))
+        Int(rows[])
+    end
+    @test sum(nrows) == 10
+    err = polars_parquet_metadata_row_group_num_rows(metadata, ngroups, Ref{Csize_t}())
+    @test_throws ErrorException Polars.polars_error(err)
+
+    schema = Ref{API.ArrowSchema}()
+    Polars.polars_error(polars_parquet_metadata_schema(metadata, schema))
+    (; names) = Polars.load_dataframe_schema(schema[])
+    @test names == (:x, :name)
+
+    @test polars_parquet_metadata_num_key_values(metadata) >= 1
+    key, keylen = Ref{Ptr{UInt8}}(), Ref{Csize_t}()
+    value, valuelen = Ref{Ptr{UInt8}}(), Ref{Csize_t}()
+    Polars.polars_error(polars_parquet_metadata_key_value(metadata, 0, key, keylen, value, valuelen))
+    @test !isempty(unsafe_string(key[], keylen[]))
+
+    stats = read_frame(polars_parquet_metadata_statistics, metadata, "x", 1)
+    @test size(stats) == (ngroups, 4)
+    @test first(stats[:min]) == 1
+    @test last(stats[:max]) == 10
+    @test_throws ErrorException read_frame(polars_parquet_metadata_statistics, metadata, "y", 1)
+
+    polars_parquet_metadata_destroy(metadata)
+end
+
+@testset "Scan hive partitioned datasets" begin
+    # Only the directories below the non-glob prefix of the pattern are partitions.
+    root = mkpath(joinpath(mktempdir(), "version=1"))
+    for (region, xs) in (("eu", [1, 2]), ("us", [3]), ("asia", [4, 5]))
+        dir = mkpath(joinpath(root, "region=$region"))
+        write_parquet(joinpath(dir, "data.parquet"), DataFrame((; x=xs)))
+        write(joinpath(dir, "data.csv"), "x\n" * join(xs, "\n") * "\n")
+    end
+
+    function scan(f, pattern, options)
+        with_strings([joinpath(root, pattern)]) do paths, pathlens, npaths
+            scan_frame(f, paths, pathlens, npaths, Ref(options))
+        end
+    end
+
+    options = with_options(polars_parquet_scan_options_default(); hive_partitioning=true)
+    df = filter(scan(polars_lazy_frame_scan_parquet_files, "**/*.parquet", options), col("region") == lit("eu")) |> collect
+    @test sort(collect(df[:x])) == [1, 2]
+    @test all(==("eu"), df[:region])
+
+    df = scan(polars_lazy_frame_scan_parquet_files, "**/*.parquet", options) |> collect
+    @test size(df) == (5, 2)
+
+    options = with_options(polars_parquet_scan_options_default(); hive_partitioning=false)
+    df = scan(polars_lazy_frame_scan_parquet_files, "**/*.parquet", options) |> collect
+    @test size(df) == (5, 1)
+
+    options = with_options(polars_csv_scan_options_default(); hive_partitioning=true)
+    df = filter(scan(polars_lazy_frame_scan_csv_files, "*/*.csv", options), col("region") == lit("asia")) |> collect
+    @test sort(collect(df[:x])) == [4, 5]
+
+    # The files of the partitions which cannot match the filter are not read.
+    write(joinpath(root, "region=us", "data.parquet"), "not a parquet file")
+    options = with_options(polars_parquet_scan_options_default(); hive_partitioning=true)
+    lf = scan(polars_lazy_frame_scan_parquet_files, "**/*.parquet", options)
+    @test_throws ErrorException collect(lf)
+    df = filter(lf, (col("region") == lit("eu")) & (col("x") > 1)) |> collect
+    @test collect(df[:x]) == [2]
+end
+
+@testset "Write hive partitioned datasets" begin
+    root = mktempdir()
+    df = DataFrame((; x=[1, 2, 3, 4, 5], region=["eu", "us", "eu", missing, "us"]))
+
+    options = polars_parquet_write_options_default()
+    err = with_strings(["region"]) do partition_by, partition_by_lens, n_partition_by
+        polars_dataframe_write_parquet_partitioned(df, root, sizeof(root),
+                                                   partition_by, partition_by_lens, n_partition_by,
+                                                   Ref(options))
+    end
+    Polars.polars_error(err)
+
+    @test isfile(joinpath(root, "region=eu", "data.parquet"))
+    @test isfile(joinpath(root, "region=__HIVE_DEFAULT_PARTITION__", "data.parquet"))
+    @test collect(read_parquet(joinpath(root, "region=us", "data.parquet"))[:x]) == [2, 5]
+
+    options = with_options(polars_parquet_scan_options_default(); hive_partitioning=true)
+    lf = with_strings([joinpath(root, "**/*.parquet")]) do paths, pathlens, npaths
+        scan_frame(polars_lazy_frame_scan_parquet_files, paths, pathlens, npaths, Ref(options))
+    end
+    @test sort(collect(collect(filter(lf, col("region") == lit("eu")))[:x])) == [1, 3]
+end
+
+@testset "Batched CSV reader" begin
+    csv = "x,name\n" * join(("$i,n$i" for i in 1:1000), "\n") * "\n"
+    path = joinpath(mktempdir(), "data.csv")
+    write(path, csv)
+
+    function read_batches(reader)
+        batches = DataFrame[]
+        out = Vector{Ptr{polars_dataframe_t}}(undef, 4)
+        out_len = Ref{Csize_t}()
+        while true
+            Polars.polars_error(polars_csv_batched_reader_next_batches(reader, length(out), out, out_len))
+            out_len[] == 0 && break
+            append!(batches, map(DataFrame, out[1:out_len[]]))
+        end
+        polars_csv_batched_reader_destroy(reader)
+        batches
+    end
+
+    options = Ref(polars_csv_read_options_default())
+    reader = Ref{Ptr{polars_csv_batched_reader_t}}()
+    Polars.polars_error(polars_csv_batched_reader_open(path, sizeof(path), options, reader))
+    batches = read_batches(reader[])
+    @test sum(first ∘ size, batches) == 1000
+    @test reduce(vcat, collect.(getindex.(batches, :x))) == 1:1000
+
+    ref = Ref(IOBuffer(csv))
+    GC.@preserve ref begin
+        err = polars_csv_batched_reader_open_from_callbacks(reader_callbacks(ref), options, reader)
+        Polars.polars_error(err)
+        batches = read_batches(reader[])
+    end
+    @test sum(first ∘ size, batches) == 1000
+end
+
+@testset "Batched parquet writer" begin
+    df1 = DataFrame((; x=[1, 2, 3], name=["a", "b", "c"]))
+    df2 = DataFrame((; x=[4, 5], name=["d", "e"]))
+    reordered = DataFrame((; name=["f"], x=[6]))
+
+    io = Ref(IOBuffer())
+    callback = @cfunction(Polars._write_callback, Cssize_t, (Any, Ptr{Cchar}, Cuint))
+    schema = Ref(polars_dataframe_schema(df1))
+    options = Ref(with_options(polars_parquet_write_options_default(); row_group_size=2))
+    writer = Ref{Ptr{polars_parquet_batched_writer_t}}()
+
+    GC.@preserve io begin
+        err = polars_parquet_batched_writer_open(schema, options, io, callback, writer)
+        @ccall $(schema[].release)(schema::Ptr{API.ArrowSchema})::Cvoid
+        Polars.polars_error(err)
+
+        Polars.polars_error(polars_parquet_batched_writer_write_batch(writer[], df1))
+        Polars.polars_error(polars_parquet_batched_writer_write_batch(writer[], df2))
+        @test_throws ErrorException Polars.polars_error(polars_parquet_batched_writer_write_batch(writer[], reordered))
+
+        Polars.polars_error(polars_parquet_batched_writer_finish(writer[]))
+        @test_throws ErrorException Polars.polars_error(polars_parquet_batched_writer_finish(writer[]))
+        polars_parquet_batched_writer_destroy(writer[])
+    end
+
+    path = joinpath(mktempdir(), "data.parquet")
+    write(path, take!(io[]))
+    df = read_parquet(path)
+    @test collect(df[:x]) == 1:5
+    @test collect(df[:name]) == ["a", "b", "c", "d", "e"]
+end
+
+@testset "Export Arrow C streams" begin
+    df = DataFrame((; x=collect(1:5), y=randn(5)))
+
+    # Consumes the stream through its callbacks, returning the column names and the row count.
+    function consume(stream::Ref{API.ArrowArrayStream})
+        schema = Ref{API.ArrowSchema}()
+        ret = @ccall $(stream[].get_schema)(stream::Ptr{API.ArrowArrayStream}, schema::Ptr{API.ArrowSchema})::Cint
+        @test ret == 0
+        (; names) = Polars.load_dataframe_schema(schema[])
+
+        nrows = 0
+        array = Ref{API.ArrowArray}()
+        while true
+            ret = @ccall $(stream[].get_next)(stream::Ptr{API.ArrowArrayStream}, array::Ptr{API.ArrowArray})::Cint
+            @test ret == 0
+            array[].release == C_NULL && break
+            nrows += array[].length
+            @ccall $(array[].release)(array::Ptr{API.ArrowArray})::Cvoid
+        end
+
+        @ccall $(stream[].release)(stream::Ptr{API.ArrowArrayStream})::Cvoid
+        names, nrows
+    end
+
+    stream = Ref{API.ArrowArrayStream}()
+    Polars.polars_error(polars_dataframe_export_carrow_stream(df, stream))
+    @test consume(stream) == ((:x, :y), 5)
+
+    lf = filter(lazy(df), col("x") > 2)
+    Polars.polars_error(polars_lazy_frame_collect_carrow_stream(lf, stream))
+    @test consume(stream) == ((:x, :y), 3)
+end
+
+@testset "Import Arrow C streams" begin
+    df = DataFrame((; x=collect(1:5), name=["a", "b", missing, "d", "e"]))
+
+    stream = Ref{API.ArrowArrayStream}()
+    Polars.polars_error(polars_dataframe_export_carrow_stream(df, stream))
+    df2 = read_frame(polars_dataframe_new_from_carrow_stream, stream)
+
+    @test stream[].release == C_NULL
+    @test size(df2) == size(df)
+    @test collect(df2[:x]) == collect(df[:x])
+    @test isequal(collect(df2[:name]), collect(df[:name]))
+end
+
+@testset "Export series chunks" begin
+    series = Series(:x, [1, 2, 3])
+    @test polars_series_num_chunks(series) == 1
+
+    array = Ref{API.ArrowArray}()
+    Polars.polars_error(polars_series_export_carrow(series, 0, array))
+    @test array[].length == 3
+    @test array[].null_count == 0
+    @ccall $(array[].release)(array::Ptr{API.ArrowArray})::Cvoid
+
+    @test_throws ErrorException Polars.polars_error(polars_series_export_carrow(series, 1, array))
+end
+
+@testset "Import series from Arrow C arrays" begin
+    series = Series(:x, [1.5, missing, 3.5])
+
+    schema = Ref(polars_series_schema(series))
+    array = Ref{API.ArrowArray}()
+    Polars.polars_error(polars_series_export_carrow(series, 0, array))
+
+    out = Ref{Ptr{polars_series_t}}()
+    err = polars_series_new_from_carrow("y", 1, schema, array[], out)
+    @ccall $(schema[].release)(schema::Ptr{API.ArrowSchema})::Cvoid
+    Polars.polars_error(err)
+
+    series2 = Series(out[])
+    @test Polars.name(series2) == "y"
+    @test isequal(collect(series2), collect(series))
+end
+
+@testset "Validated Arrow import" begin
+    validation = Ref(polars_carrow_validation_default())
+
+    array, schema = Polars.arrowtable((; s=["ab", "c", "d"]), "polars.dataframe")
+    df = try
+        read_frame(polars_dataframe_new_from_carrow_validated, schema, array, validation)
+    finally
+        Polars.release_schema!(schema)
+    end
+    @test collect(df[:s]) == ["ab", "c", "d"]
+
+    array, schema = Polars.arrowtable((; s=["ab", "c", "d"]), "polars.dataframe")
+    offsets = only(array.children).buffers[1]
+    offsets[2], offsets[3] = offsets[3], offsets[2] - 1
+    try
+        @test_throws ErrorException read_frame(polars_dataframe_new_from_carrow_validated, schema, array, validation)
+    finally
+        Polars.release_schema!(schema)
+    end
+    # The array is released even when the import fails.
+    @test !haskey(Polars.LIVE_ARRAYS, array)
+end
+
+@testset "Error kinds" begin
+    df = DataFrame((; x=[1, 2, 3]))
+
+    err = polars_dataframe_get(df, "y", 1, Ref{Ptr{polars_series_t}}())
+    @test error_kind(err) == PolarsErrorKindColumnNotFound
+
+    path = joinpath(mktempdir(), "missing.csv")
+    err = polars_dataframe_read_csv(path, sizeof(path), Ref(polars_csv_read_options_default()),
+                                    Ref{Ptr{polars_dataframe_t}}())
+    @test error_kind(err) == PolarsErrorKindIo
+
+    err = polars_series_export_carrow(df[:x], 1, Ref{API.ArrowArray}())
+    @test error_kind(err) == PolarsErrorKindOutOfBounds
+end
+
+@testset "Caught panics" begin
+    df = DataFrame((; x=[1, 2, 3]))
+
+    io = Ref(IOBuffer())
+    callback = @cfunction(Polars._write_callback, Cssize_t, (Any, Ptr{Cchar}, Cuint))
+    err = polars_dataframe_write_parquet_with_options(df, C_NULL, io, callback)
+    @test error_kind(err) == PolarsErrorKindPanic
+
+    # The library is still usable after a panic.
+    @test collect(select(df, col("x") |> sum)[:x]) == [6]
+
+    empty = polars_dataframe_new()
+    @test empty != C_NULL
+    rows, cols = Ref{Csize_t}(), Ref{Csize_t}()
+    Polars.polars_error(polars_dataframe_size_checked(empty, rows, cols))
+    @test (rows[], cols[]) == (0, 0)
+    polars_dataframe_destroy(empty)
+end
+
+@testset "Checked entry points" begin
+    df = DataFrame((; x=[1, 2, missing]))
+
+    rows, cols = Ref{Csize_t}(), Ref{Csize_t}()
+    Polars.polars_error(polars_dataframe_size_checked(df, rows, cols))
+    @test (rows[], cols[]) == (3, 1)
+
+    series = df[:x]
+    isnull = Ref{Bool}()
+    Polars.polars_error(polars_series_is_null_checked(series, 2, isnull))
+    @test isnull[]
+    @test error_kind(polars_series_is_null_checked(series, 3, isnull)) == PolarsErrorKindOutOfBounds
+    @test error_kind(polars_series_get_checked(series, 3, Ref{Ptr{polars_value_t}}())) == PolarsErrorKindOutOfBounds
+
+    a, b = lazy(df), lazy(df)
+    exprs_a, exprs_b = [col("x")], [col("x"), col("x")]
+    err = GC.@preserve exprs_a exprs_b begin
+        polars_lazy_frame_join_inner_checked(a, b,
+                                             [e.ptr for e in exprs_a], length(exprs_a),
+                                             [e.ptr for e in exprs_b], length(exprs_b),
+                                             Ref{Ptr{polars_lazy_frame_t}}())
+    end
+    @test error_kind(err) == PolarsErrorKindShapeMismatch
+
+    err = polars_expr_cast_checked(col("x"), PolarsValueTypeList, Ref{Ptr{polars_expr_t}}())
+    @test error_kind(err) == PolarsErrorKindInvalidOperation
+end
+
+@testset "Values outlive their series" begin
+    series = Series(:s, ["a", "bc", missing])
+    @test isequal(collect(series), ["a", "bc", missing])
+
+    out = Ref{Ptr{polars_value_t}}()
+    Polars.polars_error(polars_series_get_checked(series, 1, out))
+    value = out[]
+
+    finalize(series)
+    series = nothing
+    GC.gc(true)
+
+    io = Ref(IOBuffer())
+    callback = @cfunction(Polars._write_callback, Cssize_t, (Any, Ptr{Cchar}, Cuint))
+    Polars.polars_error(polars_value_utf8_get(value, io, callback))
+    @test String(take!(io[])) == "bc"
+
+    polars_value_destroy(value)
+end
+
+@testset "Values with time zones and categories" begin
+    # Polars.ArrowSchema does not support dictionaries, they are patched into the C structs.
+    function categorical(name, keys, categories)
+        dictionary = Polars.ArrowSchema(; format="u", name="")
+        schema = Polars.ArrowSchema(; format="I", name)
+        schema.dictionary = dictionary
+        schema.carrow_schema = with_options(schema.carrow_schema;
+                                            dictionary=Base.unsafe_convert(Ptr{API.ArrowSchema}, dictionary))
+        values = Polars.arrowvector(categories)
+        array = Polars.arrowvector(keys)
+        array.carrow_array = with_options(array.carrow_array;
+                                          dictionary=Base.unsafe_convert(Ptr{API.ArrowArray}, values))
+        schema, array, dictionary, values
+    end
+    datetime(name) = Polars.ArrowSchema(; format="tsu:Europe/Paris", name)
+
+    c_schema, c_array, c_dictionary, c_values = categorical("c", UInt32[1, 0, 1], ["a", "b"])
+    sc_schema, sc_array, sc_dictionary, sc_values = categorical("c", UInt32[0, 1, 0], ["a", "b"])
+    st_schema, st_array = datetime("t"), Polars.arrowvector(Int64[0, 1, 2])
+    s_schema = Polars.ArrowSchema(; format="+s", name="s", children=[st_schema, sc_schema])
+    s_array = Polars.ArrowArray(Polars.ValidityMap(3, 0, UInt8[]), [], [st_array, sc_array])
+    schema = Polars.ArrowSchema(; format="+s", name="polars.dataframe",
+                                children=[datetime("t"), c_schema, s_schema])
+    array = Polars.ArrowArray(Polars.ValidityMap(3, 0, UInt8[]), [],
+                              [Polars.arrowvector(Int64[0, 1, 2]), c_array, s_array])
+
+    validation = Ref(polars_carrow_validation_default())
+    df = try
+        read_frame(polars_dataframe_new_from_carrow_validated, schema, array, validation)
+    finally
+        foreach(Polars.release_schema!, (schema, s_schema, c_dictionary, sc_dictionary))
+    end
+
+    function value(name)
+        series = Ref{Ptr{polars_series_t}}()
+        Polars.polars_error(polars_dataframe_get(df, name, sizeof(name), series))
+        out = Ref{Ptr{polars_value_t}}()
+        err = polars_series_get_checked(series[], 0, out)
+        polars_series_destroy(series[])
+        Polars.polars_error(err)
+        out[]
+    end
+    function field(value, index)
+        out = Ref{Ptr{polars_value_t}}()
+        Polars.polars_error(polars_value_struct_get(value, index, out))
+        out[]
+    end
+    function time_zone(value)
+        out = Ref{Ptr{UInt8}}()
+        len = polars_value_time_zone(value, out)
+        out[] == C_NULL ? nothing : unsafe_string(out[], len)
+    end
+    function utf8(value)
+        io = Ref(IOBuffer())
+        callback = @cfunction(Polars._write_callback, Cssize_t, (Any, Ptr{Cchar}, Cuint))
+        Polars.polars_error(polars_value_utf8_get(value, io, callback))
+        String(take!(io[]))
+    end
+
+    t, c, s = value("t"), value("c"), value("s")
+    finalize(df)
+    foreach(Polars.release_array!, (s_array, c_values, sc_values))
+    GC.gc(true)
+
+    @test time_zone(t) == "Europe/Paris"
+    @test time_zone(c) === nothing
+    @test utf8(c) == "b"
+
+    s_t, s_c = field(s, 0), field(s, 1)
+    @test time_zone(s_t) == "Europe/Paris"
+    @test utf8(s_c) == "a"
+
+    foreach(polars_value_destroy, (t, c, s, s_t, s_c))
+end
+
+@testset "Clones are independent" begin
+    df = DataFrame((; x=[1, 2, 3]))
+    df2 = DataFrame(polars_dataframe_clone(df))
+    finalize(df)
+    @test size(df2) == (3, 1)
+    @test collect(df2[:x]) == [1, 2, 3]
+
+    series = df2[:x]
+    series2 = Series(polars_series_clone(series))
+    finalize(series)
+    @test collect(series2) == [1, 2, 3]
+
+    expr = col("x") * 2
+    expr2 = Polars.Expr(polars_expr_clone(expr))
+    finalize(expr)
+    @test collect(select(df2, expr2)[:x]) == [2, 4, 6]
+end